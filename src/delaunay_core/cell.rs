@@ -1,19 +1,23 @@
 //! Data and operations on d-dimensional cells or [simplices](https://en.wikipedia.org/wiki/Simplex).
 
 use super::{
-    facet::Facet,
-    matrix::invert,
+    facet::{Facet, FacetKey},
+    kernel::{CoordinateScalar, FilteredKernel, Kernel},
     point::Point,
-    utilities::{make_uuid, vec_to_array},
+    triangulation_data_structure::{CellKey, Tds, VertexKey},
+    utilities::make_uuid,
     vertex::Vertex,
 };
-use crate::{Coord, Coordf64};
-use na::{ComplexField, Const, OPoint};
-use nalgebra as na;
-use peroxide::fuga::*;
+use crate::Coord;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, fmt::Debug, hash::Hash, iter::Sum};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    hash::Hash,
+    marker::PhantomData,
+};
 use uuid::Uuid;
 
 #[derive(Builder, Clone, Debug, Default, Eq)]
@@ -32,41 +36,56 @@ use uuid::Uuid;
 ///
 /// # Properties:
 ///
-/// * `vertices`: A container of vertices. Each [Vertex] has a type T, optional
+/// * `vertices`: A container of the [VertexKey]s of the vertices of the
+///   [Cell] in the [Tds] holding them. Each [Vertex] has a type T, optional
 ///   data U, and a constant D representing the number of dimensions.
 /// * `uuid`: The `uuid` property is of type [Uuid] and represents a
 ///   universally unique identifier for a [Cell] in order to identify
 ///   each instance.
-/// * `neighbors`: The `neighbors` property is an optional container of [Uuid]
-///   values. It represents the [Uuid]s of the neighboring cells that are connected
+/// * `neighbors`: The `neighbors` property is an optional container of
+///   [CellKey] values. It represents the neighboring cells that are connected
 ///   to the current [Cell], indexed such that the `i-th` neighbor is opposite the
-///   `i-th`` [Vertex].
+///   `i-th` [Vertex]. The `i-th` entry is `None` if the facet opposite the
+///   `i-th` [Vertex] is on the boundary of the triangulation.
 /// * `data`: The `data` property is an optional field that can hold a value of
 ///   type `V`. It allows storage of additional data associated with the [Cell];
 ///   the data must implement [Eq], [Hash], [Ord], [PartialEq], and [PartialOrd].
-pub struct Cell<T, U, V, const D: usize>
+///
+/// As the [Cell] only holds handles to its vertices, its geometry is looked
+/// up in the [Tds], and computed by the [Kernel] `K`, which is the
+/// [FilteredKernel] by default.
+pub struct Cell<T, U, V, const D: usize, K = FilteredKernel>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
-    /// The vertices of the cell.
-    pub vertices: Vec<Vertex<T, U, D>>,
+    /// The [VertexKey]s of the vertices of the cell.
+    pub vertices: Vec<VertexKey>,
     /// The unique identifier of the cell.
     #[builder(setter(skip), default = "make_uuid()")]
     pub uuid: Uuid,
-    /// The neighboring cells connected to the current cell.
+    /// The neighboring cells connected to the current cell, where the
+    /// `i-th` neighbor is opposite the `i-th` vertex.
     #[builder(setter(skip), default = "None")]
-    pub neighbors: Option<Vec<Uuid>>,
+    pub neighbors: Option<Vec<Option<CellKey>>>,
     /// The optional data associated with the cell.
     #[builder(setter(into, strip_option), default)]
     pub data: Option<V>,
+    /// The type of the vertices of the cell.
+    #[builder(setter(skip), default)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    vertex_type: PhantomData<Vertex<T, U, D>>,
+    /// The [Kernel] computing the geometry of the cell.
+    #[builder(setter(skip), default)]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    kernel: PhantomData<K>,
 }
 
-impl<T, U, V, const D: usize> CellBuilder<T, U, V, D>
+impl<T, U, V, const D: usize, K> CellBuilder<T, U, V, D, K>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
@@ -89,20 +108,21 @@ where
     }
 }
 
-impl<T, U, V, const D: usize> Cell<T, U, V, D>
+impl<T, U, V, const D: usize, K> Cell<T, U, V, D, K>
 where
-    T: Clone + ComplexField<RealField = T> + Copy + Default + PartialEq + PartialOrd + Sum,
+    T: CoordinateScalar,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
-    f64: From<T>,
+    K: Kernel<T, D>,
     [T; D]: Coord,
 {
     /// The function `from_facet_and_vertex` creates a new [Cell] object from a [Facet] and a [Vertex].
     ///
     /// # Arguments:
     ///
+    /// * `tds`: The [Tds] holding the cell of the [Facet].
     /// * `facet`: The [Facet] to be used to create the [Cell].
-    /// * `vertex`: The [Vertex] to be added to the [Cell].
+    /// * `vertex`: The [VertexKey] of the [Vertex] to be added to the [Cell].
     ///
     /// # Returns:
     ///
@@ -113,22 +133,29 @@ where
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
     /// use dd_delaunay::delaunay_core::facet::Facet;
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1 = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).build().unwrap();
-    /// let vertex2 = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).build().unwrap();
-    /// let vertex3 = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).build().unwrap();
-    /// let vertex4 = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).build().unwrap();
-    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).build().unwrap();
-    /// let facet = Facet::new(cell.clone(), vertex4).unwrap();
-    /// let vertex5 = VertexBuilder::default().point(Point::new([0.0, 0.0, 0.0])).build().unwrap();
-    /// let new_cell = Cell::from_facet_and_vertex(facet, vertex5).unwrap();
-    /// assert!(new_cell.vertices.contains(&vertex5));
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, Option<()>, Option<()>, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    ///     Point::new([0.0, 0.0, 0.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vertices[..4].to_vec()).build().unwrap();
+    /// let mut tds = tds;
+    /// let cell = tds.add_cell(cell).unwrap();
+    /// let facet = Facet::new(&tds, cell, 3).unwrap();
+    /// let new_cell = Cell::from_facet_and_vertex(&tds, facet, vertices[4]).unwrap();
+    /// assert!(new_cell.contains_vertex(vertices[4]));
+    /// ```
     pub fn from_facet_and_vertex(
-        facet: Facet<T, U, V, D>,
-        vertex: Vertex<T, U, D>,
+        tds: &Tds<T, U, V, D, K>,
+        facet: Facet,
+        vertex: VertexKey,
     ) -> Result<Self, anyhow::Error> {
-        let mut vertices = facet.vertices();
+        let mut vertices = facet.vertices(tds)?;
         vertices.push(vertex);
         let uuid = make_uuid();
         let neighbors = None;
@@ -138,6 +165,8 @@ where
             uuid,
             neighbors,
             data,
+            vertex_type: PhantomData,
+            kernel: PhantomData,
         })
     }
 
@@ -157,12 +186,15 @@ where
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1 = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).build().unwrap();
-    /// let vertex2 = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).build().unwrap();
-    /// let vertex3 = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).build().unwrap();
-    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3]).build().unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, Option<()>, Option<()>, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vertices).build().unwrap();
     /// assert_eq!(cell.number_of_vertices(), 3);
     /// ```
     pub fn number_of_vertices(&self) -> usize {
@@ -180,12 +212,15 @@ where
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1 = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).build().unwrap();
-    /// let vertex2 = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).build().unwrap();
-    /// let vertex3 = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).build().unwrap();
-    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3]).build().unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, Option<()>, Option<()>, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vertices).build().unwrap();
     /// assert_eq!(cell.dim(), 2);
     /// ```
     pub fn dim(&self) -> usize {
@@ -193,16 +228,49 @@ where
     }
 
     /// The function is_valid checks if a [Cell] is valid.
-    /// struct.
+    ///
+    /// Whether the [Uuid] is unique, the vertices are valid and in the [Tds],
+    /// and the `neighbors` are other [Cell]s sharing the facet opposite each
+    /// [Vertex] depends on the rest of the triangulation, so those are
+    /// checked by [Tds::validate] instead.
     ///
     /// # Returns:
     ///
-    /// True if the [Cell] is valid; the `Vertices` are correct, the `UUID` is
-    /// valid and unique, the `neighbors` contains `UUID`s of neighboring
-    /// [Cell]s, and the `neighbors` are indexed such that the index of the
-    /// [Vertex] opposite the neighboring cell is the same.
-    pub fn is_valid(self) -> bool {
-        todo!("Implement is_valid for Cell")
+    /// True if the [Cell] is valid; it has between 1 and D + 1 distinct
+    /// [VertexKey]s, its [Uuid] is not nil, and the `neighbors` (if any)
+    /// have one entry per [Vertex].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, Option<()>, Option<()>, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vertices.clone()).build().unwrap();
+    /// assert!(cell.is_valid());
+    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vec![vertices[0], vertices[1], vertices[0]]).build().unwrap();
+    /// assert!(!cell.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        if self.uuid.is_nil() || self.vertices.is_empty() || self.vertices.len() > D + 1 {
+            return false;
+        }
+
+        let vertex_keys: HashSet<VertexKey> = self.vertices.iter().copied().collect();
+        if vertex_keys.len() != self.vertices.len() {
+            return false;
+        }
+
+        match &self.neighbors {
+            Some(neighbors) => neighbors.len() == self.vertices.len(),
+            None => true,
+        }
     }
 
     /// The function `contains_vertex` checks if a given vertex is present in
@@ -210,7 +278,7 @@ where
     ///
     /// # Arguments:
     ///
-    /// * vertex: The [Vertex] to check.
+    /// * vertex: The [VertexKey] of the [Vertex] to check.
     ///
     /// # Returns:
     ///
@@ -221,16 +289,19 @@ where
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
-    /// assert!(cell.contains_vertex(vertex1));
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices.clone()).data("three-one cell").build().unwrap();
+    /// assert!(cell.contains_vertex(vertices[0]));
     /// ```
-    pub fn contains_vertex(&self, vertex: Vertex<T, U, D>) -> bool {
+    pub fn contains_vertex(&self, vertex: VertexKey) -> bool {
         self.vertices.contains(&vertex)
     }
 
@@ -248,21 +319,49 @@ where
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
-    /// let vertex5: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 0.0])).data(0).build().unwrap();
-    /// let cell2: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex5]).data("one-three cell").build().unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    ///     Point::new([0.0, 0.0, 0.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices[..4].to_vec()).data("three-one cell").build().unwrap();
+    /// let cell2: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertices[0], vertices[1], vertices[2], vertices[4]]).data("one-three cell").build().unwrap();
     /// assert!(cell.contains_vertex_of(&cell2));
-    pub fn contains_vertex_of(&self, cell: &Cell<T, U, V, D>) -> bool {
+    /// ```
+    pub fn contains_vertex_of(&self, cell: &Cell<T, U, V, D, K>) -> bool {
         self.vertices.iter().any(|v| cell.vertices.contains(v))
     }
 
-    /// The function `circumcenter` returns the circumcenter of the cell.
+    /// The function `points` returns the [Point]s of the vertices of the
+    /// [Cell], in order, as stored in the [Tds].
+    ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The [Tds] holding the vertices of the [Cell].
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [Point]s, or an error if a [Vertex] of the
+    /// [Cell] is not in the [Tds], such as the infinite [Vertex].
+    pub fn points(&self, tds: &Tds<T, U, V, D, K>) -> Result<Vec<Point<T, D>>, anyhow::Error> {
+        self.vertices
+            .iter()
+            .map(|v| {
+                tds.vertices
+                    .get(*v)
+                    .map(|vertex| vertex.point.clone())
+                    .ok_or_else(|| anyhow::Error::msg("Vertex is not in the Tds!"))
+            })
+            .collect()
+    }
+
+    /// The function `circumcenter` returns the circumcenter of the cell,
+    /// as computed by the [Kernel].
     ///
     /// Using the approach from:
     ///
@@ -274,11 +373,11 @@ where
     /// The circumcenter C of a cell with vertices x_0, x_1, ..., x_n is the
     /// solution to the system:
     ///
-    /// C = 1/2 (A^-1*B)
+    /// C = x_0 + 1/2 (A^-1*B)
     ///
     /// Where:
     ///
-    /// A is a matrix (to be inverted) of the form:
+    /// A is a matrix of the form:
     ///     (x_1-x0) for all coordinates in x1, x0
     ///     (x2-x0) for all coordinates in x2, x0
     ///     ... for all x_n in the cell
@@ -288,90 +387,78 @@ where
     /// And:
     ///
     /// B is a vector of the form:
-    ///     (x_1^2-x0^2) for all coordinates in x1, x0
-    ///     (x_2^2-x0^2) for all coordinates in x2, x0
+    ///     |x_1-x0|^2
+    ///     |x_2-x0|^2
     ///     ... for all x_n in the cell
     ///
-    /// The resulting vector gives the coordinates of the circumcenter.
+    /// The resulting vector gives the coordinates of the circumcenter. Rather
+    /// than inverting A, the kernels solve the system, which the `f64`
+    /// kernels do with LU decomposition of a fixed-size matrix on the stack,
+    /// as per [solve](super::matrix::solve).
+    ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The [Tds] holding the vertices of the [Cell].
     ///
     /// # Returns:
     ///
     /// If the function is successful, it will return an Ok variant containing
-    /// the circumcenter as a Point<f64, D> value. If there is an error, it
-    /// will return an Err variant containing an error message.
+    /// the circumcenter as a Point<T::Float, D> value, i.e. in `f32` for
+    /// `f32` coordinates and `f64` otherwise. If there is an error, it will
+    /// return an Err variant containing an error message.
     ///
     /// # Example
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
-    /// let circumcenter = cell.circumcenter().unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([0.0, 0.0, 1.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices).data("three-one cell").build().unwrap();
+    /// let circumcenter = cell.circumcenter(&tds).unwrap();
     /// assert_eq!(circumcenter, Point::new([0.5, 0.5, 0.5]));
     /// ```
-    pub fn circumcenter(&self) -> Result<Point<f64, D>, anyhow::Error>
+    pub fn circumcenter(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+    ) -> Result<Point<T::Float, D>, anyhow::Error>
     where
-        [f64; D]: Coordf64,
+        [T::Float; D]: Coord,
     {
-        let dim = self.dim();
-        if self.vertices[0].dim() != dim {
-            return Err(anyhow::Error::msg("Not a simplex!"));
-        }
-
-        let mut matrix = zeros(dim, dim);
-        for i in 0..dim {
-            for j in 0..dim {
-                matrix[(i, j)] = (self.vertices[i + 1].point.coords[j]
-                    - self.vertices[0].point.coords[j])
-                    .into();
-            }
-        }
-
-        let a_inv = invert(&matrix)?;
-
-        let mut b = zeros(dim, 1);
-        for i in 0..dim {
-            b[(i, 0)] = na::distance_squared(
-                &na::Point::from(self.vertices[i + 1].point.coords),
-                &na::Point::from(self.vertices[0].point.coords),
-            )
-            .into();
-        }
-
-        let solution = a_inv * b * 0.5;
-
-        let solution_vec = solution.col(0).to_vec();
-        let solution_array = vec_to_array(solution_vec).map_err(anyhow::Error::msg)?;
-
-        let solution_point: Point<f64, D> = Point::<f64, D>::from(solution_array);
-
-        Ok(Point::<f64, D>::new(solution_point.coords))
+        K::circumcenter(&self.points(tds)?)
     }
 
     /// The function `circumradius` returns the circumradius of the cell.
     /// The circumradius is the distance from the circumcenter to any vertex.
     ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The [Tds] holding the vertices of the [Cell].
+    ///
     /// # Returns:
     ///
     /// If successful, returns an Ok containing the circumradius of the cell,
     /// otherwise returns an Err with an error message.
-    fn circumradius(&self) -> Result<T, anyhow::Error>
+    pub fn circumradius(&self, tds: &Tds<T, U, V, D, K>) -> Result<T::Float, anyhow::Error>
     where
-        OPoint<T, Const<D>>: From<[f64; D]>,
-        [f64; D]: Coordf64,
+        [T::Float; D]: Coord,
     {
-        let circumcenter = self.circumcenter()?;
-        // Change the type of vertex to match circumcenter
-        let vertex = Point::<f64, D>::from(self.vertices[0].point.coords);
-        Ok(na::distance(
-            &na::Point::<T, D>::from(circumcenter.coords),
-            &na::Point::<T, D>::from(vertex.coords),
-        ))
+        let circumcenter = self.circumcenter(tds)?;
+        let vertex = self.points(tds)?[0].coords.each_ref().map(T::to_f64);
+        let squared_radius: f64 = circumcenter
+            .coords
+            .iter()
+            .zip(vertex.iter())
+            .map(|(c, v)| (c.to_f64() - v).powi(2))
+            .sum();
+
+        Ok(T::float_from_f64(squared_radius.sqrt()))
     }
 
     /// The function `circumsphere_contains` checks if a given vertex is
@@ -379,132 +466,156 @@ where
     ///
     /// # Arguments:
     ///
+    /// * `tds`: The [Tds] holding the vertices of the [Cell].
     /// * `vertex`: vertex to check.
     ///
     /// # Returns:
     ///
     /// Returns `true` if the given [Vertex] is contained in the circumsphere
-    /// of the [Cell], and `false` otherwise.
+    /// of the [Cell] or on it, as given by the [Kernel::insphere] predicate,
+    /// and `false` otherwise. Returns an error if the [Cell] is not a
+    /// D-simplex.
     ///
     /// # Example
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices).data("three-one cell").build().unwrap();
     /// let origin: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::origin()).build().unwrap();
-    /// assert!(cell.circumsphere_contains(origin).unwrap());
+    /// assert!(cell.circumsphere_contains(&tds, origin).unwrap());
     /// ```
-    pub fn circumsphere_contains(&self, vertex: Vertex<T, U, D>) -> Result<bool, anyhow::Error>
-    where
-        OPoint<T, Const<D>>: From<[f64; D]>,
-        [f64; D]: Coordf64,
-    {
-        let circumradius = self.circumradius()?;
-        let radius = na::distance(
-            &na::Point::<T, D>::from(self.circumcenter()?.coords),
-            &na::Point::<T, D>::from(Point::<f64, D>::from(vertex.point.coords).coords),
-        );
+    pub fn circumsphere_contains(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+        vertex: Vertex<T, U, D>,
+    ) -> Result<bool, anyhow::Error> {
+        if self.vertices.len() != D + 1 {
+            return Err(anyhow::Error::msg("Not a simplex!"));
+        }
 
-        Ok(circumradius >= radius)
+        Ok(K::insphere(&self.points(tds)?, &vertex.point) != Ordering::Less)
     }
 
     /// The function `circumsphere_contains_vertex` checks if a given vertex is
     /// contained in the circumsphere of the Cell using a matrix determinant.
-    /// This method is preferred over `circumsphere_contains` as it provides better numerical
-    /// stability by using a matrix determinant approach instead of distance calculations,
-    /// which can accumulate floating-point errors.
+    /// The sign of the determinant is computed by the
+    /// [Kernel::perturbed_insphere] predicate, which is exact unless the
+    /// [Kernel] is the inexact [FastKernel](super::kernel::FastKernel), so
+    /// the answer is consistent even for nearly cospherical vertices, and a
+    /// [Vertex] exactly on the circumsphere is deterministically inside or
+    /// outside of it.
+    ///
+    /// The test evaluates the lifted determinant
+    ///
+    /// ```text
+    /// | x_0 - p    |x_0 - p|^2 |
+    /// | ...        ...         |
+    /// | x_D - p    |x_D - p|^2 |
+    /// ```
+    ///
+    /// whose sign depends on the orientation of the [Cell], so it is
+    /// multiplied by the sign of the orientation determinant
+    ///
+    /// ```text
+    /// | x_0 - p    1 |
+    /// | ...        1 |
+    /// | x_D - p    1 |
+    /// ```
+    ///
+    /// This makes the result independent of the order of the vertices.
+    /// Points on the circumsphere are inside or outside of it as given by the
+    /// symbolic perturbation, which only depends on their coordinates. The
+    /// vertices of the [Cell] are not considered contained.
     ///
     /// # Arguments:
     ///
+    /// * `tds`: The [Tds] holding the vertices of the [Cell].
     /// * `vertex`: The [Vertex] to check.
     ///
     /// # Returns:
     ///
-    /// Returns `true` if the given [Vertex] is contained in the circumsphere
-    /// of the [Cell], and `false` otherwise.
-    /// /// # Example
+    /// Returns `true` if the given [Vertex] is contained in the perturbed
+    /// circumsphere of the [Cell], and `false` otherwise. Returns an error if
+    /// the [Cell] is not a D-simplex.
+    ///
+    /// # Example
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
-    /// let origin: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::origin()).build().unwrap();
-    /// assert!(cell.circumsphere_contains(origin).unwrap());
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices).data("three-one cell").build().unwrap();
+    /// let center: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.5, 0.5, 0.5])).build().unwrap();
+    /// assert!(cell.circumsphere_contains_vertex(&tds, center).unwrap());
+    /// let far: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([2.0, 2.0, 2.0])).build().unwrap();
+    /// assert!(!cell.circumsphere_contains_vertex(&tds, far).unwrap());
     /// ```
     pub fn circumsphere_contains_vertex(
         &self,
+        tds: &Tds<T, U, V, D, K>,
         vertex: Vertex<T, U, D>,
-    ) -> Result<bool, anyhow::Error>
-    where
-        f64: From<T>,
-        [f64; D]: Coordf64,
-    {
-        // Setup initial matrix with zeros
-        let mut matrix = zeros(D + 1, D + 1);
-
-        // Populate rows with the coordinates of the vertices of the cell
-        for (i, v) in self.vertices.iter().enumerate() {
-            for j in 0..D {
-                matrix[(i, j)] = v.point.coords[j].into();
-            }
-            // Add a one to the last column
-            matrix[(i, D)] = T::one().into();
-        }
-
-        // Add the vertex to the last row of the matrix
-        for j in 0..D {
-            matrix[(D, j)] = vertex.point.coords[j].into();
+    ) -> Result<bool, anyhow::Error> {
+        if self.vertices.len() != D + 1 {
+            return Err(anyhow::Error::msg("Not a simplex!"));
         }
-        matrix[(D, D)] = T::one().into();
-
-        // Calculate the determinant of the matrix
-        let det = matrix.det();
 
-        // Check if the determinant is positive
-        Ok(det > T::zero().into())
+        Ok(K::perturbed_insphere(&self.points(tds)?, &vertex.point) == Ordering::Greater)
     }
 
-    /// The function `facets` returns the [Facet]s of the [Cell].
+    /// The function `facet_key` returns the [FacetKey] of the facet of the
+    /// [Cell] opposite its `index`-th vertex, without copying the [Cell].
     ///
     /// # Example
     ///
     /// ```
     /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// use dd_delaunay::delaunay_core::facet::Facet;
-    /// let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).data(1).build().unwrap();
-    /// let vertex2: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).data(1).build().unwrap();
-    /// let vertex3: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).data(1).build().unwrap();
-    /// let vertex4: Vertex<f64, i32, 3> = VertexBuilder::default().point(Point::new([1.0, 1.0, 1.0])).data(2).build().unwrap();
-    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).data("three-one cell").build().unwrap();
-    /// let facets = cell.facets();
-    /// assert_eq!(facets.len(), 4);
-    pub fn facets(&self) -> Vec<Facet<T, U, V, D>> {
-        let mut facets: Vec<Facet<T, U, V, D>> = Vec::new();
-        for vertex in self.vertices.iter() {
-            facets.push(Facet::new(self.clone(), *vertex).unwrap());
-        }
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, i32, &str, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 1.0, 1.0]),
+    /// ]);
+    /// let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// let cell: Cell<f64, i32, &str, 3> = CellBuilder::default().vertices(vertices.clone()).data("three-one cell").build().unwrap();
+    /// assert_eq!(cell.facet_key(0).vertices(), &vertices[1..]);
+    /// ```
+    pub fn facet_key(&self, index: usize) -> FacetKey {
+        FacetKey::new(&self.vertices, index)
+    }
 
-        facets
+    /// The function `facet_keys` returns the [FacetKey]s of all facets of the
+    /// [Cell], in the order of their opposite vertices.
+    pub fn facet_keys(&self) -> Vec<FacetKey> {
+        (0..self.vertices.len())
+            .map(|index| self.facet_key(index))
+            .collect()
     }
 }
 
-/// Equality of cells is based on equality of sorted vector of vertices.
-impl<T, U, V, const D: usize> PartialEq for Cell<T, U, V, D>
+/// Equality of cells is based on equality of sorted vector of vertex keys.
+impl<T, U, V, const D: usize, K> PartialEq for Cell<T, U, V, D, K>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
@@ -512,17 +623,18 @@ where
     #[inline]
     fn eq(&self, other: &Self) -> bool {
         let mut left = self.vertices.clone();
-        left.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        left.sort();
         let mut right = other.vertices.clone();
-        right.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        right.sort();
         left == right
     }
 }
 
-/// Order of cells is based on lexicographic order of sorted vector of vertices.
-impl<T, U, V, const D: usize> PartialOrd for Cell<T, U, V, D>
+/// Order of cells is based on lexicographic order of sorted vector of vertex
+/// keys.
+impl<T, U, V, const D: usize, K> PartialOrd for Cell<T, U, V, D, K>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
@@ -530,9 +642,9 @@ where
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         let mut left = self.vertices.clone();
-        left.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        left.sort();
         let mut right = other.vertices.clone();
-        right.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        right.sort();
         left.partial_cmp(&right)
     }
 }
@@ -543,38 +655,61 @@ mod tests {
     use super::*;
     use crate::delaunay_core::{point::Point, vertex::VertexBuilder};
 
+    /// Adds a [Vertex] for each point, with the given data, to a new [Tds],
+    /// and returns it with the keys of the vertices.
+    fn tds_with_data<V>(points: Vec<([f64; 3], i32)>) -> (Tds<f64, i32, V, 3>, Vec<VertexKey>)
+    where
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+    {
+        let mut tds: Tds<f64, i32, V, 3> = Tds::new(Vec::new());
+        let vertices = points
+            .into_iter()
+            .map(|(coords, data)| {
+                let vertex = VertexBuilder::default()
+                    .point(Point::new(coords))
+                    .data(data)
+                    .build()
+                    .unwrap();
+                tds.add(vertex).unwrap()
+            })
+            .collect();
+
+        (tds, vertices)
+    }
+
+    /// The vertices of a tetrahedron with data 1, 1, 1, and 2.
+    fn three_one_points() -> Vec<([f64; 3], i32)> {
+        vec![
+            ([0.0, 0.0, 1.0], 1),
+            ([0.0, 1.0, 0.0], 1),
+            ([1.0, 0.0, 0.0], 1),
+            ([1.0, 1.0, 1.0], 2),
+        ]
+    }
+
+    /// The vertices of the unit tetrahedron at the origin.
+    fn unit_points() -> Vec<([f64; 3], i32)> {
+        vec![
+            ([0.0, 0.0, 0.0], 1),
+            ([1.0, 0.0, 0.0], 1),
+            ([0.0, 1.0, 0.0], 1),
+            ([0.0, 0.0, 1.0], 2),
+        ]
+    }
+
     #[test]
     fn cell_build() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+        let (tds, vertices) = tds_with_data::<Option<()>>(three_one_points());
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices.clone())
             .build()
             .unwrap();
 
-        assert_eq!(cell.vertices, [vertex1, vertex2, vertex3, vertex4]);
-        assert_eq!(cell.vertices[0].data.unwrap(), 1);
-        assert_eq!(cell.vertices[1].data.unwrap(), 1);
-        assert_eq!(cell.vertices[2].data.unwrap(), 1);
-        assert_eq!(cell.vertices[3].data.unwrap(), 2);
+        assert_eq!(cell.vertices, vertices);
+        assert_eq!(tds.vertices[cell.vertices[0]].data.unwrap(), 1);
+        assert_eq!(tds.vertices[cell.vertices[1]].data.unwrap(), 1);
+        assert_eq!(tds.vertices[cell.vertices[2]].data.unwrap(), 1);
+        assert_eq!(tds.vertices[cell.vertices[3]].data.unwrap(), 2);
         assert_eq!(cell.dim(), 3);
         assert_eq!(cell.number_of_vertices(), 4);
         assert!(cell.neighbors.is_none());
@@ -593,34 +728,11 @@ mod tests {
 
     #[test]
     fn cell_build_with_too_many_vertices() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::new([2.0, 2.0, 2.0]))
-            .data(3)
-            .build()
-            .unwrap();
-        let cell: Result<Cell<f64, i32, Option<()>, 3>, CellBuilderError> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4, vertex5])
-            .build();
+        let mut points = three_one_points();
+        points.push(([2.0, 2.0, 2.0], 3));
+        let (_, vertices) = tds_with_data::<Option<()>>(points);
+        let cell: Result<Cell<f64, i32, Option<()>, 3>, CellBuilderError> =
+            CellBuilder::default().vertices(vertices).build();
 
         assert!(cell.is_err());
 
@@ -630,37 +742,15 @@ mod tests {
 
     #[test]
     fn cell_build_with_data() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+        let (tds, vertices) = tds_with_data::<&str>(three_one_points());
         let cell: Cell<f64, i32, &str, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices.clone())
             .data("three-one cell")
             .build()
             .unwrap();
 
-        assert_eq!(cell.vertices, [vertex1, vertex2, vertex3, vertex4]);
-        assert_eq!(cell.vertices[0].data.unwrap(), 1);
-        assert_eq!(cell.vertices[1].data.unwrap(), 1);
-        assert_eq!(cell.vertices[2].data.unwrap(), 1);
-        assert_eq!(cell.vertices[3].data.unwrap(), 2);
+        assert_eq!(cell.vertices, vertices);
+        assert_eq!(tds.vertices[cell.vertices[3]].data.unwrap(), 2);
         assert_eq!(cell.dim(), 3);
         assert_eq!(cell.number_of_vertices(), 4);
         assert!(cell.neighbors.is_none());
@@ -673,28 +763,9 @@ mod tests {
 
     #[test]
     fn cell_clone() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+        let (_, vertices) = tds_with_data::<&str>(three_one_points());
         let cell1: Cell<f64, i32, &str, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices)
             .data("three-one cell")
             .build()
             .unwrap();
@@ -705,41 +776,22 @@ mod tests {
 
     #[test]
     fn cell_from_facet_and_vertex() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+        let mut points = three_one_points();
+        points.push(([0.0, 0.0, 0.0], 0));
+        let (mut tds, vertices) = tds_with_data::<Option<()>>(points);
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices[..4].to_vec())
             .build()
             .unwrap();
-        let facet = Facet::new(cell.clone(), vertex4).unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::origin())
-            .build()
-            .unwrap();
-        let new_cell = Cell::from_facet_and_vertex(facet, vertex5).unwrap();
+        let cell = tds.add_cell(cell).unwrap();
+        let facet = Facet::new(&tds, cell, 3).unwrap();
+        let new_cell = Cell::from_facet_and_vertex(&tds, facet, vertices[4]).unwrap();
 
-        assert!(new_cell.vertices.contains(&vertex1));
-        assert!(new_cell.vertices.contains(&vertex2));
-        assert!(new_cell.vertices.contains(&vertex3));
-        assert!(new_cell.vertices.contains(&vertex5));
+        assert!(new_cell.contains_vertex(vertices[0]));
+        assert!(new_cell.contains_vertex(vertices[1]));
+        assert!(new_cell.contains_vertex(vertices[2]));
+        assert!(!new_cell.contains_vertex(vertices[3]));
+        assert!(new_cell.contains_vertex(vertices[4]));
 
         // Human readable output for cargo test -- --nocapture
         println!("New Cell: {:?}", new_cell);
@@ -747,30 +799,9 @@ mod tests {
 
     #[test]
     fn cell_into_hashmap() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
-        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
+        let (_, vertices) = tds_with_data::<Option<()>>(three_one_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
         let hashmap = Cell::into_hashmap(vec![cell.clone()]);
         let values: Vec<Cell<f64, i32, Option<()>, 3>> = hashmap.into_values().collect();
 
@@ -784,20 +815,9 @@ mod tests {
 
     #[test]
     fn cell_number_of_vertices() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
+        let (_, vertices) = tds_with_data::<Option<()>>(three_one_points());
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3])
+            .vertices(vertices[..3].to_vec())
             .build()
             .unwrap();
 
@@ -806,20 +826,9 @@ mod tests {
 
     #[test]
     fn cell_dim() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
+        let (_, vertices) = tds_with_data::<Option<()>>(three_one_points());
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3])
+            .vertices(vertices[..3].to_vec())
             .build()
             .unwrap();
 
@@ -827,36 +836,51 @@ mod tests {
     }
 
     #[test]
-    fn cell_contains_vertex() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
+    fn cell_is_valid() {
+        let (_, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
+            .vertices(vertices.clone())
             .build()
             .unwrap();
+
+        assert!(cell.is_valid());
+
+        let mut nil_cell = cell.clone();
+        nil_cell.uuid = Uuid::nil();
+        assert!(!nil_cell.is_valid());
+
+        let mut too_many_vertices = cell.clone();
+        too_many_vertices.vertices.push(VertexKey::default());
+        assert!(!too_many_vertices.is_valid());
+
+        let mut repeated_vertex = cell.clone();
+        repeated_vertex.vertices[0] = vertices[1];
+        assert!(!repeated_vertex.is_valid());
+
+        let mut wrong_neighbor_count = cell.clone();
+        wrong_neighbor_count.neighbors = Some(vec![None; 3]);
+        assert!(!wrong_neighbor_count.is_valid());
+
+        let mut neighbors = cell.clone();
+        neighbors.neighbors = Some(vec![Some(CellKey::default()), None, None, None]);
+        assert!(neighbors.is_valid());
+    }
+
+    #[test]
+    fn cell_contains_vertex() {
+        let mut points = three_one_points();
+        points.push(([0.0, 0.0, 0.0], 0));
+        let (_, vertices) = tds_with_data::<Option<()>>(points);
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices[..4].to_vec())
             .build()
             .unwrap();
 
-        assert!(cell.contains_vertex(vertex1));
-        assert!(cell.contains_vertex(vertex2));
-        assert!(cell.contains_vertex(vertex3));
-        assert!(cell.contains_vertex(vertex4));
+        assert!(cell.contains_vertex(vertices[0]));
+        assert!(cell.contains_vertex(vertices[1]));
+        assert!(cell.contains_vertex(vertices[2]));
+        assert!(cell.contains_vertex(vertices[3]));
+        assert!(!cell.contains_vertex(vertices[4]));
 
         // Human readable output for cargo test -- --nocapture
         println!("Cell: {:?}", cell);
@@ -864,61 +888,67 @@ mod tests {
 
     #[test]
     fn cell_contains_vertex_of() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+        let mut points = three_one_points();
+        points.push(([0.0, 0.0, 0.0], 0));
+        let (_, vertices) = tds_with_data::<&str>(points);
         let cell: Cell<f64, i32, &str, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices[..4].to_vec())
             .data("three-one cell")
             .build()
             .unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::origin())
-            .data(0)
-            .build()
-            .unwrap();
         let cell2 = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex5])
+            .vertices(vec![vertices[0], vertices[1], vertices[2], vertices[4]])
             .data("one-three cell")
             .build()
             .unwrap();
+        let cell3 = CellBuilder::default()
+            .vertices(vec![vertices[4]])
+            .build()
+            .unwrap();
 
         assert!(cell.contains_vertex_of(&cell2));
+        assert!(!cell.contains_vertex_of(&cell3));
 
         // Human readable output for cargo test -- --nocapture
         println!("Cell: {:?}", cell);
     }
 
     #[test]
-    fn cell_circumcenter() {
-        let points = vec![
-            Point::new([0.0, 0.0, 0.0]),
-            Point::new([1.0, 0.0, 0.0]),
-            Point::new([0.0, 1.0, 0.0]),
-            Point::new([0.0, 0.0, 1.0]),
-        ];
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(Vertex::from_points(points))
+    fn cell_points() {
+        let (mut tds, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
+            .vertices(vertices.clone())
             .build()
             .unwrap();
-        let circumcenter = cell.circumcenter().unwrap();
+
+        assert_eq!(
+            cell.points(&tds).unwrap(),
+            unit_points()
+                .into_iter()
+                .map(|(coords, _)| Point::new(coords))
+                .collect::<Vec<_>>()
+        );
+
+        // Moving a vertex moves the cell, which only refers to it
+        tds.vertices[vertices[0]].point = Point::new([-1.0, -1.0, -1.0]);
+        assert_eq!(
+            cell.points(&tds).unwrap()[0],
+            Point::new([-1.0, -1.0, -1.0])
+        );
+
+        // Vertices which are not in the Tds have no point
+        let mut missing = cell.clone();
+        missing.vertices[0] = VertexKey::default();
+        assert!(missing.points(&tds).is_err());
+        assert!(missing.circumcenter(&tds).is_err());
+    }
+
+    #[test]
+    fn cell_circumcenter() {
+        let (tds, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
+        let circumcenter = cell.circumcenter(&tds).unwrap();
 
         assert_eq!(circumcenter, Point::new([0.5, 0.5, 0.5]));
 
@@ -927,58 +957,46 @@ mod tests {
     }
 
     #[test]
-    fn cell_circumcenter_fail() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
+    fn cell_circumcenter_f32() {
+        let tds: Tds<f32, Option<()>, Option<()>, 3> = Tds::new(vec![
+            Point::new([1.0f32, 1.0, 1.0]),
+            Point::new([2.0, 1.0, 1.0]),
+            Point::new([1.0, 2.0, 1.0]),
+            Point::new([1.0, 1.0, 2.0]),
+        ]);
+        let cell: Cell<f32, Option<()>, Option<()>, 3> = CellBuilder::default()
+            .vertices(tds.vertices.keys().collect())
             .build()
             .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
+        let circumcenter: Point<f32, 3> = cell.circumcenter(&tds).unwrap();
+        let center: Vertex<f32, Option<()>, 3> = VertexBuilder::default()
+            .point(circumcenter)
             .build()
             .unwrap();
+
+        assert_eq!(circumcenter, Point::new([1.5, 1.5, 1.5]));
+        assert_eq!(cell.circumradius(&tds).unwrap(), 0.75f32.sqrt());
+        assert!(cell.circumsphere_contains_vertex(&tds, center).unwrap());
+    }
+
+    #[test]
+    fn cell_circumcenter_fail() {
+        let (tds, vertices) = tds_with_data::<Option<()>>(unit_points());
         let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3])
+            .vertices(vertices[..3].to_vec())
             .build()
             .unwrap();
-        let circumcenter = cell.circumcenter();
+        let circumcenter = cell.circumcenter(&tds);
 
         assert!(circumcenter.is_err());
     }
 
     #[test]
     fn cell_circumradius() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
-        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let circumradius = cell.circumradius().unwrap();
+        let (tds, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
+        let circumradius = cell.circumradius(&tds).unwrap();
         let radius: f64 = 3.0_f64.sqrt() / 2.0;
 
         assert_eq!(circumradius, radius);
@@ -989,37 +1007,16 @@ mod tests {
 
     #[test]
     fn cell_circumsphere_contains() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
-        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
+        let (tds, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
         let vertex5 = VertexBuilder::default()
             .point(Point::new([1.0, 1.0, 1.0]))
             .data(3)
             .build()
             .unwrap();
 
-        assert!(cell.circumsphere_contains(vertex5).unwrap());
+        assert!(cell.circumsphere_contains(&tds, vertex5).unwrap());
 
         // Human readable output for cargo test -- --nocapture
         println!("Cell: {:?}", cell);
@@ -1027,76 +1024,36 @@ mod tests {
 
     #[test]
     fn cell_circumsphere_does_not_contain() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
-        let cell: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
+        let (tds, vertices) = tds_with_data::<Option<()>>(unit_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
         let vertex5 = VertexBuilder::default()
             .point(Point::new([2.0, 2.0, 2.0]))
             .data(3)
             .build()
             .unwrap();
 
-        assert!(!cell.circumsphere_contains(vertex5).unwrap());
+        assert!(!cell.circumsphere_contains(&tds, vertex5).unwrap());
 
         // Human readable output for cargo test -- --nocapture
         println!("Cell: {:?}", cell);
     }
 
     #[test]
-    fn cell_facets_contains() {
-        let vertex1: Vertex<f64, i32, 3> = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .data(1)
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .data(2)
-            .build()
-            .unwrap();
+    fn cell_facet_keys() {
+        let (_, vertices) = tds_with_data::<Option<&str>>(three_one_points());
         let cell: Cell<f64, i32, Option<&str>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vertices.clone())
             .data("three-one cell")
             .build()
             .unwrap();
-        let facets = cell.facets();
+        let facets = cell.facet_keys();
 
         assert_eq!(facets.len(), 4);
-        for facet in facets.iter() {
-            // assert!(cell.facets().contains(facet));
-            let facet_vertices = facet.vertices();
-            assert!(cell.facets().iter().any(|f| f.vertices() == facet_vertices));
+        for (index, facet) in facets.iter().enumerate() {
+            assert_eq!(facet.vertices().len(), 3);
+            assert!(!facet.vertices().contains(&vertices[index]));
+            assert!(facet.vertices().windows(2).all(|w| w[0] < w[1]));
         }
 
         // Human readable output for cargo test -- --nocapture
@@ -1105,37 +1062,19 @@ mod tests {
 
     #[test]
     fn cell_to_and_from_json() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
+        let (_, vertices) = tds_with_data::<Option<()>>(three_one_points());
+        let cell: Cell<f64, i32, Option<()>, 3> =
+            CellBuilder::default().vertices(vertices).build().unwrap();
         let serialized = serde_json::to_string(&cell).unwrap();
 
-        assert!(serialized.contains("[0.0,0.0,1.0]"));
-        assert!(serialized.contains("[0.0,1.0,0.0]"));
-        assert!(serialized.contains("[1.0,0.0,0.0]"));
-        assert!(serialized.contains("[1.0,1.0,1.0]"));
+        assert!(serialized.contains(r#"{"idx":1,"version":1}"#));
+        assert!(serialized.contains(r#"{"idx":4,"version":1}"#));
 
-        let deserialized: Cell<f64, Option<()>, Option<()>, 3> =
+        let deserialized: Cell<f64, i32, Option<()>, 3> =
             serde_json::from_str(&serialized).unwrap();
 
         assert_eq!(deserialized, cell);
+        assert_eq!(deserialized.vertices, cell.vertices);
 
         // Human readable output for cargo test -- --nocapture
         println!("Serialized: {:?}", serialized);
@@ -1143,40 +1082,23 @@ mod tests {
 
     #[test]
     fn cell_partial_eq() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::origin())
-            .build()
-            .unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell1: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+        let mut points = three_one_points();
+        points.push(([0.0, 0.0, 0.0], 0));
+        let (_, v) = tds_with_data::<Option<()>>(points);
+        let cell1: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
+            .vertices(vec![v[0], v[1], v[2], v[4]])
             .build()
             .unwrap();
         let cell2 = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+            .vertices(vec![v[0], v[1], v[2], v[4]])
             .build()
             .unwrap();
         let cell3 = CellBuilder::default()
-            .vertices(vec![vertex4, vertex3, vertex2, vertex1])
+            .vertices(vec![v[4], v[2], v[1], v[0]])
             .build()
             .unwrap();
         let cell4 = CellBuilder::default()
-            .vertices(vec![vertex5, vertex4, vertex3, vertex2])
+            .vertices(vec![v[3], v[4], v[2], v[1]])
             .build()
             .unwrap();
 
@@ -1190,39 +1112,23 @@ mod tests {
 
     #[test]
     fn cell_partial_ord() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::origin())
-            .build()
-            .unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell1: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
+        let mut points = three_one_points();
+        points.push(([0.0, 0.0, 0.0], 0));
+        let (_, v) = tds_with_data::<Option<()>>(points);
+        let cell1: Cell<f64, i32, Option<()>, 3> = CellBuilder::default()
+            .vertices(vec![v[0], v[1], v[2], v[4]])
             .build()
             .unwrap();
         let cell2 = CellBuilder::default()
-            .vertices(vec![vertex4, vertex3, vertex2, vertex1])
+            .vertices(vec![v[4], v[2], v[1], v[0]])
             .build()
             .unwrap();
         let cell3 = CellBuilder::default()
-            .vertices(vec![vertex5, vertex4, vertex3, vertex2])
+            .vertices(vec![v[3], v[4], v[2], v[1]])
             .build()
             .unwrap();
 
+        // Sorted vertex keys are compared in order of insertion
         assert!(cell1 < cell3);
         assert!(cell2 < cell3);
         assert!(cell3 > cell1);
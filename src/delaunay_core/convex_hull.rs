@@ -0,0 +1,858 @@
+//! The convex hull of a set of vertices, as a complex of consistently
+//! oriented (d-1)-dimensional facets.
+//!
+//! The hull of a [Tds] is read off its triangulation: the facets of the
+//! finite cells without a finite neighbor across them are exactly the
+//! facets of the convex hull, so no separate hull computation is needed
+//! (cf. [Tds::convex_hull]).
+//!
+//! When there is no Delaunay triangulation to begin with, the hull of a set
+//! of points is computed directly with
+//! [Quickhull](https://doi.org/10.1145/235815.235821) by
+//! [ConvexHull::from_points], which is output sensitive, and does not need a
+//! [Tds] at all.
+
+use super::{
+    facet::Facet,
+    kernel::{CoordinateScalar, FilteredKernel, Kernel},
+    matrix::{determinant, solve},
+    point::Point,
+    triangulation_data_structure::{Tds, VertexKey},
+};
+use crate::Coord;
+use std::{cmp::Ordering, collections::HashMap, hash::Hash};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The [Hyperplane] struct is an oriented hyperplane in D-dimensional
+/// space, given by its unit `normal` and `offset`, as the points `x` with
+/// `normal . x = offset`.
+///
+/// # Properties:
+///
+/// * `normal`: The unit normal, pointing to the positive side.
+/// * `offset`: The signed distance of the hyperplane from the origin along
+///   the `normal`.
+pub struct Hyperplane<F, const D: usize> {
+    /// The unit normal, pointing to the positive side.
+    pub normal: [F; D],
+    /// The signed distance of the hyperplane from the origin.
+    pub offset: F,
+}
+
+impl<F, const D: usize> Hyperplane<F, D>
+where
+    F: CoordinateScalar,
+{
+    /// The function `signed_distance` returns the signed distance of a
+    /// [Point] from the [Hyperplane], computed in `f64`, which is positive
+    /// on the side its `normal` points to.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::convex_hull::Hyperplane;
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// let plane = Hyperplane { normal: [0.0, 1.0], offset: 2.0 };
+    /// assert_eq!(plane.signed_distance(&Point::new([5.0, 3.0])), 1.0);
+    /// assert_eq!(plane.signed_distance(&Point::new([5.0, 0.0])), -2.0);
+    /// ```
+    pub fn signed_distance<T>(&self, point: &Point<T, D>) -> f64
+    where
+        T: CoordinateScalar,
+        [T; D]: Coord,
+    {
+        self.normal
+            .iter()
+            .zip(point.coords.iter())
+            .map(|(n, c)| n.to_f64() * c.to_f64())
+            .sum::<f64>()
+            - self.offset.to_f64()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// The [HullFacet] struct is a (D-1)-simplex on the boundary of a
+/// [ConvexHull].
+///
+/// # Properties:
+///
+/// * `facet`: The [Facet] of the finite [Cell](super::cell::Cell) of the
+///   [Tds] on the hull, or `None` if the hull was computed from points.
+/// * `vertices`: The D vertices of the facet, as [VertexKey]s of the [Tds]
+///   or indices of the points. In 2 or more dimensions, they are ordered so
+///   that appending any [Point] beyond the facet gives D + 1 positively
+///   oriented points, as per [Kernel::orientation].
+/// * `hyperplane`: The [Hyperplane] through the facet, whose `normal` points
+///   out of the hull.
+/// * `neighbors`: The indices in [ConvexHull::facets] of the adjacent hull
+///   facets, where the `i-th` neighbor shares the ridge opposite the `i-th`
+///   vertex.
+pub struct HullFacet<F, const D: usize, V = VertexKey> {
+    /// The [Facet] of the finite cell on the hull, if any.
+    pub facet: Option<Facet>,
+    /// The positively oriented vertices of the facet.
+    pub vertices: Vec<V>,
+    /// The [Hyperplane] through the facet, with outward normal.
+    pub hyperplane: Hyperplane<F, D>,
+    /// The indices of the adjacent hull facets, opposite each vertex.
+    pub neighbors: Vec<usize>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// The [ConvexHull] struct is the boundary of the convex hull of the
+/// vertices of a [Tds], or of a set of points, as a complex of
+/// [HullFacet]s whose vertices are [VertexKey]s or indices of the points
+/// respectively.
+///
+/// In 2 or more dimensions, the facets are consistently oriented: two
+/// adjacent facets induce opposite orientations on the ridge they share.
+/// The hull of a [Tds] without cells, or of points which span a lower
+/// dimensional affine subspace, has no facets.
+pub struct ConvexHull<F, const D: usize, V = VertexKey> {
+    /// The facets of the hull.
+    pub facets: Vec<HullFacet<F, D, V>>,
+}
+
+impl<F, const D: usize> ConvexHull<F, D>
+where
+    F: CoordinateScalar,
+{
+    /// The function `from_tds` extracts the [ConvexHull] of the vertices of
+    /// a triangulation.
+    ///
+    /// The hull facets are the facets of the finite cells whose neighbor
+    /// across them is missing or infinite. Their vertices are ordered so
+    /// that the [Vertex](super::vertex::Vertex) of the [Cell]
+    /// opposite the facet, which is inside the hull, is on their negative
+    /// side, and facets sharing a ridge are adjacent.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The [Tds] to extract the hull of.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [ConvexHull], or an error if the
+    /// `neighbors` of the cells are not assigned, a hull facet is
+    /// degenerate, or a ridge is not shared by exactly two hull facets.
+    pub fn from_tds<T, U, V, K>(tds: &Tds<T, U, V, D, K>) -> Result<Self, anyhow::Error>
+    where
+        T: CoordinateScalar<Float = F>,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let mut facets: Vec<HullFacet<F, D>> = Vec::new();
+        for (key, cell) in tds.finite_cells() {
+            let neighbors = cell
+                .neighbors
+                .as_ref()
+                .ok_or_else(|| anyhow::Error::msg("Neighbors are not assigned!"))?;
+            for (index, neighbor) in neighbors.iter().enumerate() {
+                if neighbor
+                    .is_some_and(|n| tds.cells.get(n).is_some_and(|cell| !tds.is_infinite(cell)))
+                {
+                    continue;
+                }
+
+                let facet = Facet { cell: key, index };
+                let mut vertices = facet.vertices(tds)?;
+                let points: Vec<Point<T, D>> = vertices
+                    .iter()
+                    .map(|v| tds.vertices[*v].point.clone())
+                    .collect();
+                let inside = tds.vertices[facet.vertex(tds)?].point.clone();
+
+                // The inside vertex is on the negative side
+                let simplex = [points.as_slice(), std::slice::from_ref(&inside)].concat();
+                if K::orientation(&simplex) == Ordering::Greater && vertices.len() > 1 {
+                    vertices.swap(0, 1);
+                }
+
+                facets.push(HullFacet {
+                    facet: Some(facet),
+                    vertices,
+                    hyperplane: hyperplane(&points, &inside)?,
+                    neighbors: Vec::new(),
+                });
+            }
+        }
+        link_facets(&mut facets)?;
+
+        Ok(ConvexHull { facets })
+    }
+}
+
+impl<F, const D: usize> ConvexHull<F, D, usize>
+where
+    F: CoordinateScalar,
+{
+    /// The function `from_points` computes the [ConvexHull] of a set of
+    /// points with Quickhull, on the predicates of the [FilteredKernel], as
+    /// per [ConvexHull::from_points_with_kernel].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::convex_hull::ConvexHull;
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// let points = vec![
+    ///     Point::new([0.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([0.0, 0.0, 1.0]),
+    ///     Point::new([0.1, 0.1, 0.1]),
+    ///     Point::new([0.2, 0.1, 0.3]),
+    /// ];
+    /// let hull = ConvexHull::from_points(&points).unwrap();
+    /// assert_eq!(hull.number_of_facets(), 4);
+    /// assert_eq!(hull.vertices(), vec![0, 1, 2, 3]);
+    /// assert!(hull.contains(&Point::new([0.2, 0.2, 0.2]), 0.0));
+    /// ```
+    pub fn from_points<T>(points: &[Point<T, D>]) -> Result<Self, anyhow::Error>
+    where
+        T: CoordinateScalar<Float = F>,
+        [T; D]: Coord,
+    {
+        Self::from_points_with_kernel::<T, FilteredKernel>(points)
+    }
+
+    /// The function `from_points_with_kernel` computes the [ConvexHull] of a
+    /// set of points with Quickhull, without building a [Tds].
+    ///
+    /// Starting from a simplex of D + 1 affinely independent points, each
+    /// hull facet keeps the points beyond it, as per the
+    /// [Kernel::orientation] predicate. The furthest point beyond a facet is
+    /// then joined to the horizon of the facets visible from it by new
+    /// facets, until no point is outside of the hull. Points on the
+    /// boundary of the hull which are not extreme points need not be
+    /// vertices of the hull.
+    ///
+    /// # Arguments:
+    ///
+    /// * `points`: The points to compute the hull of, which the vertices of
+    ///   the [HullFacet]s are indices of.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [ConvexHull], which has no facets if the
+    /// points span a lower dimensional affine subspace, or an error if a
+    /// hull facet is degenerate, or a ridge is not shared by exactly two
+    /// hull facets.
+    pub fn from_points_with_kernel<T, K>(points: &[Point<T, D>]) -> Result<Self, anyhow::Error>
+    where
+        T: CoordinateScalar<Float = F>,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let subset = |indices: &[usize]| -> Vec<Point<T, D>> {
+            indices.iter().map(|i| points[*i].clone()).collect()
+        };
+        let hull = quickhull(
+            points.len(),
+            D,
+            |indices| K::affinely_independent(&subset(indices)),
+            |indices| K::orientation(&subset(indices)),
+            |indices| {
+                let last = points[indices[D]].coords.each_ref().map(T::to_f64);
+                determinant::<D>(std::array::from_fn(|i| {
+                    std::array::from_fn(|j| points[indices[i]].coords[j].to_f64() - last[j])
+                }))
+            },
+        );
+
+        // Each hull facet is a facet of one of the cells placed by
+        // Quickhull, whose vertex opposite it is inside the hull
+        let mut opposite: HashMap<Vec<usize>, usize> = HashMap::new();
+        for cell in hull.cells.iter() {
+            for (i, vertex) in cell.iter().enumerate() {
+                let mut facet = cell.clone();
+                facet.remove(i);
+                facet.sort_unstable();
+                opposite.insert(facet, *vertex);
+            }
+        }
+
+        let mut facets: Vec<HullFacet<F, D, usize>> = Vec::new();
+        for (mut vertices, outer) in hull.facets {
+            let mut sorted = vertices.clone();
+            sorted.sort_unstable();
+            let inside = *opposite
+                .get(&sorted)
+                .ok_or_else(|| anyhow::Error::msg("Hull facet is not a facet of a cell!"))?;
+
+            // The points beyond the facet are on the positive side
+            if outer == Ordering::Less && vertices.len() > 1 {
+                vertices.swap(0, 1);
+            }
+
+            facets.push(HullFacet {
+                facet: None,
+                hyperplane: hyperplane(&subset(&vertices), &points[inside])?,
+                vertices,
+                neighbors: Vec::new(),
+            });
+        }
+        link_facets(&mut facets)?;
+
+        Ok(ConvexHull { facets })
+    }
+}
+
+impl<F, const D: usize, V> ConvexHull<F, D, V>
+where
+    F: CoordinateScalar,
+    V: Copy + Ord,
+{
+    /// The function `vertices` returns the vertices on the hull, sorted and
+    /// without duplicates.
+    pub fn vertices(&self) -> Vec<V> {
+        let mut vertices: Vec<V> = self
+            .facets
+            .iter()
+            .flat_map(|facet| facet.vertices.iter().copied())
+            .collect();
+        vertices.sort();
+        vertices.dedup();
+        vertices
+    }
+
+    /// The function `number_of_facets` returns the number of facets of the
+    /// hull.
+    pub fn number_of_facets(&self) -> usize {
+        self.facets.len()
+    }
+
+    /// The function `contains` checks if a [Point] is inside the hull or on
+    /// its boundary, up to a tolerance on its distance to each facet
+    /// [Hyperplane].
+    pub fn contains<T>(&self, point: &Point<T, D>, tolerance: f64) -> bool
+    where
+        T: CoordinateScalar,
+        [T; D]: Coord,
+    {
+        !self.facets.is_empty()
+            && self
+                .facets
+                .iter()
+                .all(|facet| facet.hyperplane.signed_distance(point) <= tolerance)
+    }
+}
+
+/// Links the hull facets sharing a ridge as neighbors.
+fn link_facets<F, const D: usize, V>(facets: &mut [HullFacet<F, D, V>]) -> Result<(), anyhow::Error>
+where
+    V: Copy + Ord + Hash,
+{
+    let mut ridges: HashMap<Vec<V>, Vec<(usize, usize)>> = HashMap::new();
+    for (f, facet) in facets.iter().enumerate() {
+        for i in 0..facet.vertices.len() {
+            let mut ridge = facet.vertices.clone();
+            ridge.remove(i);
+            ridge.sort();
+            ridges.entry(ridge).or_default().push((f, i));
+        }
+    }
+    for facet in facets.iter_mut() {
+        facet.neighbors = vec![0; facet.vertices.len()];
+    }
+    for sharing in ridges.values() {
+        let [(first, first_index), (second, second_index)] = sharing.as_slice() else {
+            return Err(anyhow::Error::msg(
+                "Ridge is not shared by exactly two hull facets!",
+            ));
+        };
+        facets[*first].neighbors[*first_index] = *second;
+        facets[*second].neighbors[*second_index] = *first;
+    }
+
+    Ok(())
+}
+
+/// Returns the [Hyperplane] through the D vertices of a facet, with its
+/// normal pointing away from the `inside` [Point]. The normal `n` is the
+/// solution of
+///
+/// ```text
+/// n . (x_i - x_0) = 0
+/// n . (inside - x_0) = -1
+/// ```
+///
+/// normalized to unit length.
+fn hyperplane<T, const D: usize>(
+    points: &[Point<T, D>],
+    inside: &Point<T, D>,
+) -> Result<Hyperplane<T::Float, D>, anyhow::Error>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    let origin = points[0].coords.each_ref().map(T::to_f64);
+    let rows: [[f64; D]; D] = std::array::from_fn(|i| {
+        let point = points.get(i + 1).unwrap_or(inside);
+        std::array::from_fn(|j| point.coords[j].to_f64() - origin[j])
+    });
+    let mut rhs = [0.0; D];
+    rhs[D - 1] = -1.0;
+    let normal = solve(rows, rhs).ok_or_else(|| anyhow::Error::msg("Degenerate facet!"))?;
+
+    let length = normal.iter().map(|n| n * n).sum::<f64>().sqrt();
+    let normal = normal.map(|n| n / length);
+    let offset = normal.iter().zip(origin.iter()).map(|(n, x)| n * x).sum();
+
+    Ok(Hyperplane {
+        normal: normal.map(T::float_from_f64),
+        offset: T::float_from_f64(offset),
+    })
+}
+
+/// The result of [quickhull], as indices of the points.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Quickhull {
+    /// The cells of the triangulation of the hull placed by Quickhull.
+    pub(crate) cells: Vec<Vec<usize>>,
+    /// The facets of the hull, with the orientation of the points beyond
+    /// each facet when appended to its vertices.
+    pub(crate) facets: Vec<(Vec<usize>, Ordering)>,
+}
+
+/// A facet of a hull under construction by [quickhull].
+#[derive(Clone, Debug)]
+struct QuickhullFacet {
+    /// The indices of the vertices.
+    vertices: Vec<usize>,
+    /// The orientation of the points beyond the facet when appended to its
+    /// vertices.
+    outer: Ordering,
+    /// The facets sharing the ridge opposite each vertex.
+    neighbors: Vec<usize>,
+    /// The points beyond the facet, which are not beyond an earlier facet.
+    outside: Vec<usize>,
+    /// Whether the facet was removed from the hull.
+    removed: bool,
+}
+
+/// Computes the convex hull of `count` points in `dimension`-dimensional
+/// space with Quickhull, using the predicates on the points with the given
+/// indices:
+///
+/// * `independent`: whether up to `dimension + 1` points are affinely
+///   independent.
+/// * `orient`: the exact orientation of `dimension + 1` points.
+/// * `volume`: a floating-point approximation of the signed volume of
+///   `dimension + 1` points, which only chooses the furthest point beyond a
+///   facet.
+///
+/// A point is beyond a facet if it is strictly on its outer side. Points on
+/// the hull which are not beyond any facet are not vertices of the hull.
+///
+/// The points need not be in a Euclidean space of that dimension, as long
+/// as the predicates are those of such points, which is how the Delaunay
+/// triangulation is computed as the lower hull of the points lifted onto a
+/// paraboloid.
+pub(crate) fn quickhull(
+    count: usize,
+    dimension: usize,
+    independent: impl Fn(&[usize]) -> bool,
+    orient: impl Fn(&[usize]) -> Ordering,
+    volume: impl Fn(&[usize]) -> f64,
+) -> Quickhull {
+    let mut simplex: Vec<usize> = Vec::new();
+    for i in 0..count {
+        if simplex.len() == dimension + 1 {
+            break;
+        }
+        simplex.push(i);
+        if !independent(&simplex) {
+            simplex.pop();
+        }
+    }
+    if simplex.len() < dimension + 1 {
+        return Quickhull::default();
+    }
+
+    let with = |vertices: &[usize], point: usize| -> Vec<usize> {
+        vertices
+            .iter()
+            .copied()
+            .chain(std::iter::once(point))
+            .collect()
+    };
+    let beyond =
+        |facet: &QuickhullFacet, point: usize| orient(&with(&facet.vertices, point)) == facet.outer;
+
+    // The facets of the first simplex, with the vertex opposite each inside
+    let mut facets: Vec<QuickhullFacet> = (0..=dimension)
+        .map(|i| {
+            let vertices: Vec<usize> = simplex
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, v)| *v)
+                .collect();
+            let outer = orient(&with(&vertices, simplex[i])).reverse();
+            QuickhullFacet {
+                vertices,
+                outer,
+                neighbors: vec![0; dimension],
+                outside: Vec::new(),
+                removed: false,
+            }
+        })
+        .collect();
+    link_ridges(&mut facets, &(0..=dimension).collect::<Vec<usize>>());
+    let mut cells = vec![simplex.clone()];
+
+    for point in (0..count).filter(|p| !simplex.contains(p)) {
+        if let Some(facet) = facets.iter_mut().find(|f| beyond(f, point)) {
+            facet.outside.push(point);
+        }
+    }
+
+    let mut pending: Vec<usize> = (0..=dimension).collect();
+    while let Some(start) = pending.pop() {
+        if facets[start].removed || facets[start].outside.is_empty() {
+            continue;
+        }
+
+        // The furthest point beyond the facet
+        let eye = facets[start]
+            .outside
+            .iter()
+            .copied()
+            .max_by(|a, b| {
+                let vertices = &facets[start].vertices;
+                volume(&with(vertices, *a))
+                    .abs()
+                    .total_cmp(&volume(&with(vertices, *b)).abs())
+            })
+            .unwrap_or_default();
+
+        // The facets visible from it, which are connected
+        let mut visible: Vec<usize> = vec![start];
+        facets[start].removed = true;
+        let mut horizon: Vec<(usize, usize)> = Vec::new();
+        let mut index = 0;
+        while index < visible.len() {
+            let facet = visible[index];
+            index += 1;
+            for i in 0..dimension {
+                let neighbor = facets[facet].neighbors[i];
+                if facets[neighbor].removed {
+                    continue;
+                }
+                if beyond(&facets[neighbor], eye) {
+                    facets[neighbor].removed = true;
+                    visible.push(neighbor);
+                } else {
+                    horizon.push((facet, i));
+                }
+            }
+        }
+
+        // Join the eye to the horizon ridges and the visible facets
+        let first = facets.len();
+        for (facet, i) in horizon {
+            let mut vertices = facets[facet].vertices.clone();
+            vertices[i] = eye;
+            let neighbor = facets[facet].neighbors[i];
+            let new = facets.len();
+            if let Some(j) = facets[neighbor].neighbors.iter().position(|n| *n == facet) {
+                facets[neighbor].neighbors[j] = new;
+            }
+            let mut neighbors = vec![0; dimension];
+            neighbors[i] = neighbor;
+            facets.push(QuickhullFacet {
+                vertices,
+                outer: facets[facet].outer,
+                neighbors,
+                outside: Vec::new(),
+                removed: false,
+            });
+        }
+        let new: Vec<usize> = (first..facets.len()).collect();
+        link_ridges(&mut facets, &new);
+        for facet in visible.iter() {
+            cells.push(with(&facets[*facet].vertices, eye));
+        }
+
+        // The points beyond the visible facets may be beyond the new ones
+        let outside: Vec<usize> = visible
+            .iter()
+            .flat_map(|f| std::mem::take(&mut facets[*f].outside))
+            .filter(|p| *p != eye)
+            .collect();
+        for point in outside {
+            if let Some(f) = new.iter().find(|f| beyond(&facets[**f], point)) {
+                facets[*f].outside.push(point);
+            }
+        }
+        pending.extend(new);
+    }
+
+    let facets = facets
+        .into_iter()
+        .filter(|f| !f.removed)
+        .map(|f| (f.vertices, f.outer))
+        .collect();
+
+    Quickhull { cells, facets }
+}
+
+/// Links the given facets to each other across the ridges they share. The
+/// neighbors across the other ridges are left as they are.
+fn link_ridges(facets: &mut [QuickhullFacet], linked: &[usize]) {
+    let mut ridges: HashMap<Vec<usize>, (usize, usize)> = HashMap::new();
+    for facet in linked.iter() {
+        for i in 0..facets[*facet].vertices.len() {
+            let mut ridge: Vec<usize> = facets[*facet].vertices.clone();
+            ridge.remove(i);
+            ridge.sort_unstable();
+            match ridges.remove(&ridge) {
+                Some((other, j)) => {
+                    facets[*facet].neighbors[i] = other;
+                    facets[other].neighbors[j] = *facet;
+                }
+                None => {
+                    ridges.insert(ridge, (*facet, i));
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::delaunay_core::kernel::ExactKernel;
+    use crate::delaunay_core::test_utilities::random_points;
+    use std::fmt::Debug;
+
+    /// The corners of the unit cube in D dimensions, with points inside.
+    fn cube<const D: usize>() -> Vec<Point<f64, D>>
+    where
+        [f64; D]: Coord,
+    {
+        let corners =
+            (0..1 << D).map(|i| Point::new(std::array::from_fn(|j| ((i >> j) & 1) as f64)));
+        let inside = (1..8).map(|i| {
+            Point::new(std::array::from_fn(|j| {
+                ((i * (j + 3)) % 8) as f64 / 10.0 + 0.1
+            }))
+        });
+        corners.chain(inside).collect()
+    }
+
+    /// Returns the facets of a hull as sorted lists of vertex coordinates,
+    /// given by `point`, to compare hulls of the same points.
+    fn facet_coordinates<const D: usize, V>(
+        hull: &ConvexHull<f64, D, V>,
+        point: impl Fn(V) -> Point<f64, D>,
+    ) -> Vec<Vec<[u64; D]>>
+    where
+        V: Copy,
+        [f64; D]: Coord,
+    {
+        let mut facets: Vec<Vec<[u64; D]>> = hull
+            .facets
+            .iter()
+            .map(|facet| {
+                let mut vertices: Vec<[u64; D]> = facet
+                    .vertices
+                    .iter()
+                    .map(|v| point(*v).coords.map(f64::to_bits))
+                    .collect();
+                vertices.sort();
+                vertices
+            })
+            .collect();
+        facets.sort();
+        facets
+    }
+
+    /// Checks the facets, whose vertices are at the [Point]s given by
+    /// `point`, are adjacent across their ridges, consistently oriented, and
+    /// have every one of the `points` on or inside their hyperplane.
+    fn check_hull<const D: usize, K, V>(
+        hull: &ConvexHull<f64, D, V>,
+        points: &[Point<f64, D>],
+        point: impl Fn(V) -> Point<f64, D>,
+    ) where
+        K: Kernel<f64, D>,
+        V: Copy + Debug + Ord,
+        [f64; D]: Coord,
+    {
+        let ridge = |vertices: &[V], i: usize| {
+            let mut ridge = vertices.to_vec();
+            ridge.remove(i);
+            ridge.sort();
+            ridge
+        };
+        for (f, facet) in hull.facets.iter().enumerate() {
+            assert_eq!(facet.vertices.len(), D);
+            for (i, neighbor) in facet.neighbors.iter().enumerate() {
+                let other = &hull.facets[*neighbor];
+                let j = other.neighbors.iter().position(|n| *n == f).unwrap();
+                assert_eq!(ridge(&facet.vertices, i), ridge(&other.vertices, j));
+
+                // The ridge has opposite orientations in the two facets, so
+                // putting the vertex opposite it into the other facet reverses
+                // the orientation of the facet
+                let mut replaced = other.vertices.clone();
+                replaced[j] = facet.vertices[i];
+                let first = point(facet.vertices[0]);
+                let outside = Point::new(std::array::from_fn(|k| {
+                    first.coords[k] + facet.hyperplane.normal[k]
+                }));
+                let orientation = |vertices: &[V]| {
+                    let mut simplex: Vec<Point<f64, D>> =
+                        vertices.iter().map(|v| point(*v)).collect();
+                    simplex.push(outside);
+                    K::orientation(&simplex)
+                };
+                assert_eq!(orientation(&facet.vertices), Ordering::Greater);
+                assert_eq!(orientation(&replaced), Ordering::Less);
+            }
+            for p in points.iter() {
+                assert!(facet.hyperplane.signed_distance(p) < 1e-12);
+            }
+        }
+    }
+
+    /// Checks the hull of a [Tds], as per [check_hull].
+    fn check_tds_hull<const D: usize, K>(
+        tds: &Tds<f64, usize, usize, D, K>,
+        hull: &ConvexHull<f64, D>,
+    ) where
+        K: Kernel<f64, D>,
+        [f64; D]: Coord,
+    {
+        let points: Vec<Point<f64, D>> = tds.vertices.values().map(|v| v.point).collect();
+        check_hull::<D, K, VertexKey>(hull, &points, |v| tds.vertices[v].point);
+    }
+
+    #[test]
+    fn convex_hull_square() {
+        let tds: Tds<f64, usize, usize, 2> = Tds::new(cube()).bowyer_watson().unwrap();
+        let hull = tds.convex_hull().unwrap();
+
+        assert_eq!(hull.number_of_facets(), 4);
+        assert_eq!(hull.vertices().len(), 4);
+        check_tds_hull(&tds, &hull);
+    }
+
+    #[test]
+    fn convex_hull_cube() {
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(cube()).bowyer_watson().unwrap();
+        let hull = tds.convex_hull().unwrap();
+
+        // Each square face is split into two triangles
+        assert_eq!(hull.number_of_facets(), 12);
+        assert_eq!(hull.vertices().len(), 8);
+        for facet in hull.facets.iter() {
+            assert_eq!(
+                facet
+                    .hyperplane
+                    .normal
+                    .iter()
+                    .filter(|n| **n != 0.0)
+                    .count(),
+                1
+            );
+            assert!(facet.hyperplane.offset == 0.0 || facet.hyperplane.offset == 1.0);
+        }
+        check_tds_hull(&tds, &hull);
+
+        // The infinite cells are the neighbors across the hull facets
+        let mut infinite = tds.clone();
+        infinite.add_infinite_cells().unwrap();
+        let infinite_hull = infinite.convex_hull().unwrap();
+        assert_eq!(infinite_hull.number_of_facets(), 12);
+        assert_eq!(infinite_hull.vertices(), hull.vertices());
+    }
+
+    #[test]
+    fn convex_hull_tesseract() {
+        let tds: Tds<f64, usize, usize, 4, ExactKernel> = Tds::new(cube()).bowyer_watson().unwrap();
+        let hull = tds.convex_hull().unwrap();
+
+        assert_eq!(hull.vertices().len(), 16);
+        assert!(hull.contains(&Point::new([0.5; 4]), 0.0));
+        assert!(!hull.contains(&Point::new([1.5, 0.5, 0.5, 0.5]), 0.0));
+        check_tds_hull(&tds, &hull);
+    }
+
+    #[test]
+    fn convex_hull_without_cells() {
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(vec![
+            Point::new([0.0, 0.0, 0.0]),
+            Point::new([1.0, 1.0, 1.0]),
+        ])
+        .bowyer_watson()
+        .unwrap();
+        let hull = tds.convex_hull().unwrap();
+
+        assert_eq!(hull.number_of_facets(), 0);
+        assert!(!hull.contains(&Point::new([0.0, 0.0, 0.0]), 0.0));
+    }
+
+    #[test]
+    fn convex_hull_from_points() {
+        // In general position, the hull is unique, and the same as that of
+        // the Delaunay triangulation
+        let points: Vec<Point<f64, 3>> = random_points(200, 7, 1.0);
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(points.clone()).bowyer_watson().unwrap();
+        let hull = ConvexHull::from_points(&points).unwrap();
+
+        check_hull::<3, FilteredKernel, usize>(&hull, &points, |i| points[i]);
+        assert_eq!(
+            facet_coordinates(&hull, |i| points[i]),
+            facet_coordinates(&ConvexHull::from_tds(&tds).unwrap(), |v| tds.vertices[v]
+                .point)
+        );
+        assert!(hull.facets.iter().all(|f| f.facet.is_none()));
+
+        let points: Vec<Point<f64, 4>> = random_points(100, 8, 1.0);
+        let tds: Tds<f64, usize, usize, 4> = Tds::new(points.clone()).bowyer_watson().unwrap();
+        let hull = ConvexHull::from_points_with_kernel::<f64, ExactKernel>(&points).unwrap();
+
+        check_hull::<4, ExactKernel, usize>(&hull, &points, |i| points[i]);
+        assert_eq!(
+            facet_coordinates(&hull, |i| points[i]),
+            facet_coordinates(&ConvexHull::from_tds(&tds).unwrap(), |v| tds.vertices[v]
+                .point)
+        );
+    }
+
+    #[test]
+    fn convex_hull_from_points_degenerate() {
+        // Points on the faces and edges of the hull, which are coplanar
+        let grid: Vec<Point<f64, 3>> = (0..64)
+            .map(|i| Point::new([(i % 4) as f64, (i / 4 % 4) as f64, (i / 16) as f64]))
+            .collect();
+        let hull = ConvexHull::from_points(&grid).unwrap();
+
+        check_hull::<3, FilteredKernel, usize>(&hull, &grid, |i| grid[i]);
+        for (i, point) in grid.iter().enumerate() {
+            let corner = point.coords.iter().all(|c| *c == 0.0 || *c == 3.0);
+            let interior = point.coords.iter().all(|c| *c == 1.0 || *c == 2.0);
+            if corner {
+                assert!(hull.vertices().contains(&i));
+            }
+            if interior {
+                assert!(!hull.vertices().contains(&i));
+            }
+        }
+
+        // Collinear points have no hull facets
+        let line = ConvexHull::from_points(&[
+            Point::new([0.0, 0.0]),
+            Point::new([1.0, 1.0]),
+            Point::new([2.0, 2.0]),
+        ])
+        .unwrap();
+        assert_eq!(line.number_of_facets(), 0);
+    }
+}
@@ -4,57 +4,55 @@
 //! This provides convenience methods used in the
 //! [Bowyer-Watson algorithm](https://en.wikipedia.org/wiki/Bowyer–Watson_algorithm).
 //! Facets are not stored in the `Triangulation Data Structure` (TDS)
-//! directly, but created on the fly when needed.
+//! directly, but created on the fly when needed as lightweight handles, and
+//! identified by their [FacetKey].
 
-use super::{cell::Cell, vertex::Vertex};
+use super::{
+    kernel::{CoordinateScalar, Kernel},
+    triangulation_data_structure::{CellKey, Tds, VertexKey},
+};
 use crate::Coord;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 use std::hash::Hash;
 use thiserror::Error;
 
-#[derive(Clone, Debug, Default, Eq, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
 /// The [Facet] struct represents a facet of a d-dimensional simplex.
-/// Passing in a [Vertex] and a [Cell] containing that vertex to the
-/// constructor will create a [Facet] struct.
+/// Passing in the [CellKey] of a [Cell](super::cell::Cell) in a [Tds] and
+/// the index of one of its vertices to the constructor will create a
+/// [Facet] struct.
 ///
 /// # Properties
 ///
-/// * `cell` - The [Cell] that contains this facet.
-/// * `vertex` - The [Vertex] in the [Cell] opposite to this [Facet].
+/// * `cell` - The [CellKey] of the [Cell](super::cell::Cell) that contains
+///   this facet.
+/// * `index` - The index of the vertex in the [Cell](super::cell::Cell)
+///   opposite to this [Facet].
 ///
-/// Note that `D` is the dimensionality of the [Cell] and [Vertex];
-/// the [Facet] is one dimension less than the [Cell] (co-dimension 1).
-pub struct Facet<T, U, V, const D: usize>
-where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
-    U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
-    V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
-    [T; D]: Coord,
-{
-    /// The [Cell] that contains this facet.
-    pub cell: Cell<T, U, V, D>,
-
-    /// The [Vertex] opposite to this facet.
-    pub vertex: Vertex<T, U, D>,
+/// A [Facet] is a handle, which is only meaningful for the [Tds] holding its
+/// [Cell](super::cell::Cell). A facet shared by two cells has a [Facet] in
+/// each of them, and both have the same [FacetKey].
+pub struct Facet {
+    /// The [CellKey] of the cell that contains this facet.
+    pub cell: CellKey,
+
+    /// The index of the vertex opposite to this facet in its cell.
+    pub index: usize,
 }
 
-impl<T, U, V, const D: usize> Facet<T, U, V, D>
-where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
-    U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
-    V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
-    [T; D]: Coord,
-{
+impl Facet {
     /// The `new` function is a constructor for the [Facet]. It takes
-    /// in a [Cell] and a [Vertex] as arguments and returns a [Result]
+    /// in a [Tds], the [CellKey] of one of its cells, and the index of one of
+    /// the vertices of that cell as arguments and returns a [Result]
     /// containing a [Facet] or an error message.
     ///
     /// # Arguments
     ///
-    /// * `cell`: The [Cell] that contains the [Facet].
-    /// * `vertex`: The [Vertex] opposite to the [Facet].
+    /// * `tds`: The [Tds] holding the cell.
+    /// * `cell`: The [CellKey] of the cell that contains the [Facet].
+    /// * `index`: The index of the vertex opposite to the [Facet].
     ///
     /// # Returns
     ///
@@ -64,39 +62,179 @@ where
     /// # Example
     ///
     /// ```
-    /// use dd_delaunay::delaunay_core::cell::{Cell, CellBuilder};
     /// use dd_delaunay::delaunay_core::facet::Facet;
     /// use dd_delaunay::delaunay_core::point::Point;
-    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
-    /// let vertex1 = VertexBuilder::default().point(Point::new([0.0, 0.0, 0.0])).build().unwrap();
-    /// let vertex2 = VertexBuilder::default().point(Point::new([1.0, 0.0, 0.0])).build().unwrap();
-    /// let vertex3 = VertexBuilder::default().point(Point::new([0.0, 1.0, 0.0])).build().unwrap();
-    /// let vertex4 = VertexBuilder::default().point(Point::new([0.0, 0.0, 1.0])).build().unwrap();
-    /// let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default().vertices(vec![vertex1, vertex2, vertex3, vertex4]).build().unwrap();
-    /// let facet = Facet::new(cell.clone(), vertex1).unwrap();
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::Tds;
+    /// let tds: Tds<f64, Option<()>, Option<()>, 3> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0, 0.0]),
+    ///     Point::new([1.0, 0.0, 0.0]),
+    ///     Point::new([0.0, 1.0, 0.0]),
+    ///     Point::new([0.0, 0.0, 1.0]),
+    /// ]).bowyer_watson().unwrap();
+    /// let cell = tds.cells.keys().next().unwrap();
+    /// let facet = Facet::new(&tds, cell, 0).unwrap();
     /// assert_eq!(facet.cell, cell);
+    /// assert_eq!(facet.vertices(&tds).unwrap().len(), 3);
     /// ```
-    pub fn new(cell: Cell<T, U, V, D>, vertex: Vertex<T, U, D>) -> Result<Self, anyhow::Error> {
-        if !cell.vertices.contains(&vertex) {
+    pub fn new<T, U, V, const D: usize, K>(
+        tds: &Tds<T, U, V, D, K>,
+        cell: CellKey,
+        index: usize,
+    ) -> Result<Self, anyhow::Error>
+    where
+        T: CoordinateScalar,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let vertices = &tds
+            .cells
+            .get(cell)
+            .ok_or(FacetError::CellNotFound)?
+            .vertices;
+
+        if index >= vertices.len() {
             return Err(FacetError::CellDoesNotContainVertex.into());
         }
 
-        if cell.vertices.len() == 1 {
+        if vertices.len() == 1 {
             return Err(FacetError::CellIsZeroSimplex.into());
         }
 
-        Ok(Facet { cell, vertex })
+        Ok(Facet { cell, index })
     }
 
-    /// The `vertices` method in the [Facet] returns a container of
-    /// [Vertex] objects that are in the [Facet].
-    pub fn vertices(&self) -> Vec<Vertex<T, U, D>> {
-        self.cell
+    /// The `vertex` method in the [Facet] returns the [VertexKey] of the
+    /// vertex opposite to the [Facet].
+    ///
+    /// # Arguments
+    ///
+    /// * `tds`: The [Tds] holding the cell of the [Facet].
+    ///
+    /// # Returns
+    ///
+    /// A [Result] containing the [VertexKey], or an error if the cell is no
+    /// longer in the [Tds].
+    pub fn vertex<T, U, V, const D: usize, K>(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+    ) -> Result<VertexKey, anyhow::Error>
+    where
+        T: CoordinateScalar,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        tds.cells
+            .get(self.cell)
+            .and_then(|cell| cell.vertices.get(self.index).copied())
+            .ok_or_else(|| FacetError::CellNotFound.into())
+    }
+
+    /// The `vertices` method in the [Facet] returns the [VertexKey]s of the
+    /// vertices that are in the [Facet], in the order of its cell.
+    ///
+    /// # Arguments
+    ///
+    /// * `tds`: The [Tds] holding the cell of the [Facet].
+    ///
+    /// # Returns
+    ///
+    /// A [Result] containing the [VertexKey]s, or an error if the cell is no
+    /// longer in the [Tds].
+    pub fn vertices<T, U, V, const D: usize, K>(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+    ) -> Result<Vec<VertexKey>, anyhow::Error>
+    where
+        T: CoordinateScalar,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let cell = tds.cells.get(self.cell).ok_or(FacetError::CellNotFound)?;
+
+        Ok(cell
             .vertices
             .iter()
-            .filter(|v| **v != self.vertex)
-            .cloned()
-            .collect()
+            .enumerate()
+            .filter(|(i, _)| *i != self.index)
+            .map(|(_, v)| *v)
+            .collect())
+    }
+
+    /// The `key` method in the [Facet] returns its [FacetKey], which is the
+    /// same for both cells sharing the facet.
+    ///
+    /// # Arguments
+    ///
+    /// * `tds`: The [Tds] holding the cell of the [Facet].
+    ///
+    /// # Returns
+    ///
+    /// A [Result] containing the [FacetKey], or an error if the cell is no
+    /// longer in the [Tds].
+    pub fn key<T, U, V, const D: usize, K>(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+    ) -> Result<FacetKey, anyhow::Error>
+    where
+        T: CoordinateScalar,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let cell = tds.cells.get(self.cell).ok_or(FacetError::CellNotFound)?;
+
+        Ok(FacetKey::new(&cell.vertices, self.index))
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+/// The [FacetKey] struct is the canonical identity of a facet, given by
+/// the sorted [VertexKey]s of its vertices. It does not depend on the cell
+/// the facet is seen from, so facets shared by neighboring cells are found
+/// by hashing their keys.
+pub struct FacetKey(Vec<VertexKey>);
+
+impl FacetKey {
+    /// The `new` function returns the [FacetKey] of the facet of a simplex
+    /// with the given vertices opposite its `index`-th vertex.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::facet::FacetKey;
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::{Tds, VertexKey};
+    /// let tds: Tds<f64, Option<()>, Option<()>, 2> = Tds::new(vec![
+    ///     Point::new([0.0, 0.0]),
+    ///     Point::new([1.0, 0.0]),
+    ///     Point::new([0.0, 1.0]),
+    /// ]);
+    /// let v: Vec<VertexKey> = tds.vertices.keys().collect();
+    /// assert_eq!(FacetKey::new(&[v[0], v[1], v[2]], 0), FacetKey::new(&[v[2], v[0], v[1]], 1));
+    /// ```
+    pub fn new(vertices: &[VertexKey], index: usize) -> Self {
+        let mut key: Vec<VertexKey> = vertices
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| *i != index)
+            .map(|(_, v)| *v)
+            .collect();
+        key.sort();
+
+        FacetKey(key)
+    }
+
+    /// The `vertices` method returns the sorted [VertexKey]s of the facet.
+    pub fn vertices(&self) -> &[VertexKey] {
+        &self.0
     }
 }
 
@@ -109,38 +247,44 @@ pub enum FacetError {
     /// The cell is a 0-simplex with no facet.
     #[error("The cell is a 0-simplex with no facet!")]
     CellIsZeroSimplex,
+    /// The cell is not in the triangulation data structure.
+    #[error("The cell is not in the Tds!")]
+    CellNotFound,
 }
 #[cfg(test)]
 mod tests {
 
     use super::*;
-    use crate::delaunay_core::{cell::CellBuilder, point::Point, vertex::VertexBuilder};
+    use crate::delaunay_core::{cell::CellBuilder, point::Point};
+
+    type TestTds = Tds<f64, Option<()>, Option<()>, 3>;
+
+    /// Returns the triangulation of a unit tetrahedron, and the keys of its
+    /// vertices and cell.
+    fn tetrahedron() -> (TestTds, Vec<VertexKey>, CellKey) {
+        let tds: TestTds = Tds::new(vec![
+            Point::new([0.0, 0.0, 0.0]),
+            Point::new([1.0, 0.0, 0.0]),
+            Point::new([0.0, 1.0, 0.0]),
+            Point::new([0.0, 0.0, 1.0]),
+        ])
+        .bowyer_watson()
+        .unwrap();
+        let vertices: Vec<VertexKey> = tds.vertices.keys().collect();
+        let cell = tds.cells.keys().next().unwrap();
+
+        (tds, vertices, cell)
+    }
 
     #[test]
     fn facet_new() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let facet = Facet::new(cell.clone(), vertex1).unwrap();
+        let (tds, vertices, cell) = tetrahedron();
+        let facet = Facet::new(&tds, cell, 0).unwrap();
 
         assert_eq!(facet.cell, cell);
+        assert_eq!(facet.index, 0);
+        assert_eq!(facet.vertex(&tds).unwrap(), tds.cells[cell].vertices[0]);
+        assert!(vertices.contains(&facet.vertex(&tds).unwrap()));
 
         // Human readable output for cargo test -- --nocapture
         println!("Facet: {:?}", facet);
@@ -148,115 +292,95 @@ mod tests {
 
     #[test]
     fn facet_new_with_incorrect_vertex() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let vertex5 = VertexBuilder::default()
-            .point(Point::new([1.0, 1.0, 1.0]))
-            .build()
-            .unwrap();
+        let (tds, _, cell) = tetrahedron();
 
-        assert!(Facet::new(cell.clone(), vertex5).is_err());
+        assert!(Facet::new(&tds, cell, 4).is_err());
+        assert!(Facet::new(&tds, CellKey::default(), 0).is_err());
     }
 
     #[test]
     fn facet_new_with_1_simplex() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1])
+        let (mut tds, vertices, _) = tetrahedron();
+        let cell = CellBuilder::default()
+            .vertices(vec![vertices[0]])
             .build()
             .unwrap();
+        let cell = tds.add_cell(cell).unwrap();
 
-        assert!(Facet::new(cell.clone(), vertex1).is_err());
+        assert!(Facet::new(&tds, cell, 0).is_err());
     }
 
     #[test]
     fn facet_vertices() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let facet = Facet::new(cell.clone(), vertex1).unwrap();
-        let vertices = facet.clone().vertices();
+        let (tds, _, cell) = tetrahedron();
+        let facet = Facet::new(&tds, cell, 0).unwrap();
+        let facet_vertices = facet.vertices(&tds).unwrap();
+        let cell_vertices = &tds.cells[cell].vertices;
 
-        assert_eq!(vertices.len(), 3);
-        assert_eq!(vertices[0], vertex2);
-        assert_eq!(vertices[1], vertex3);
-        assert_eq!(vertices[2], vertex4);
+        assert_eq!(facet_vertices.len(), 3);
+        assert_eq!(facet_vertices[0], cell_vertices[1]);
+        assert_eq!(facet_vertices[1], cell_vertices[2]);
+        assert_eq!(facet_vertices[2], cell_vertices[3]);
 
         // Human readable output for cargo test -- --nocapture
         println!("Facet: {:?}", facet);
     }
 
+    #[test]
+    fn facet_key() {
+        let tds: Tds<f64, Option<()>, Option<()>, 2> = Tds::new(vec![
+            Point::new([0.0, 0.0]),
+            Point::new([1.0, 0.0]),
+            Point::new([0.0, 1.0]),
+            Point::new([1.0, 1.1]),
+        ])
+        .bowyer_watson()
+        .unwrap();
+        let (cell, other) = {
+            let mut keys = tds.cells.keys();
+            (keys.next().unwrap(), keys.next().unwrap())
+        };
+
+        // The diagonal is the only facet shared by the two cells
+        let keys = |cell: CellKey| -> Vec<FacetKey> {
+            (0..3)
+                .map(|i| Facet::new(&tds, cell, i).unwrap().key(&tds).unwrap())
+                .collect()
+        };
+        let shared: Vec<FacetKey> = keys(cell)
+            .into_iter()
+            .filter(|key| keys(other).contains(key))
+            .collect();
+        assert_eq!(shared.len(), 1);
+
+        let mut vertices = Facet::new(&tds, cell, 0).unwrap().vertices(&tds).unwrap();
+        vertices.sort();
+        assert_eq!(
+            Facet::new(&tds, cell, 0)
+                .unwrap()
+                .key(&tds)
+                .unwrap()
+                .vertices(),
+            vertices
+        );
+    }
+
     #[test]
     fn facet_to_and_from_json() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let facet = Facet::new(cell.clone(), vertex1).unwrap();
+        let (tds, _, cell) = tetrahedron();
+        let facet = Facet::new(&tds, cell, 1).unwrap();
         let serialized = serde_json::to_string(&facet).unwrap();
 
-        assert!(serialized.contains("[1.0,0.0,0.0]"));
-        assert!(serialized.contains("[0.0,1.0,0.0]"));
-        assert!(serialized.contains("[0.0,0.0,1.0]"));
+        assert!(serialized.contains(r#""cell":{"idx":1,"version":1}"#));
+        assert!(serialized.contains(r#""index":1"#));
 
-        let deserialized: Facet<f64, Option<()>, Option<()>, 3> =
-            serde_json::from_str(&serialized).unwrap();
+        let deserialized: Facet = serde_json::from_str(&serialized).unwrap();
 
         assert_eq!(deserialized, facet);
+        assert_eq!(
+            deserialized.vertices(&tds).unwrap(),
+            facet.vertices(&tds).unwrap()
+        );
 
         // Human readable output for cargo test -- --nocapture
         println!("Serialized = {:?}", serialized);
@@ -264,29 +388,10 @@ mod tests {
 
     #[test]
     fn facet_partial_eq() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let facet1 = Facet::new(cell.clone(), vertex1).unwrap();
-        let facet2 = Facet::new(cell.clone(), vertex1).unwrap();
-        let facet3 = Facet::new(cell.clone(), vertex2).unwrap();
+        let (tds, _, cell) = tetrahedron();
+        let facet1 = Facet::new(&tds, cell, 0).unwrap();
+        let facet2 = Facet::new(&tds, cell, 0).unwrap();
+        let facet3 = Facet::new(&tds, cell, 1).unwrap();
 
         assert_eq!(facet1, facet2);
         assert_ne!(facet1, facet3);
@@ -294,35 +399,18 @@ mod tests {
 
     #[test]
     fn facet_partial_ord() {
-        let vertex1 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex2 = VertexBuilder::default()
-            .point(Point::new([1.0, 0.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex3 = VertexBuilder::default()
-            .point(Point::new([0.0, 1.0, 0.0]))
-            .build()
-            .unwrap();
-        let vertex4 = VertexBuilder::default()
-            .point(Point::new([0.0, 0.0, 1.0]))
-            .build()
-            .unwrap();
-        let cell: Cell<f64, Option<()>, Option<()>, 3> = CellBuilder::default()
-            .vertices(vec![vertex1, vertex2, vertex3, vertex4])
-            .build()
-            .unwrap();
-        let facet1 = Facet::new(cell.clone(), vertex1).unwrap();
-        let facet2 = Facet::new(cell.clone(), vertex1).unwrap();
-        let facet3 = Facet::new(cell.clone(), vertex2).unwrap();
-        let facet4 = Facet::new(cell.clone(), vertex3).unwrap();
+        let (tds, _, cell) = tetrahedron();
+        let facet1 = Facet::new(&tds, cell, 0).unwrap();
+        let facet2 = Facet::new(&tds, cell, 0).unwrap();
+        let facet3 = Facet::new(&tds, cell, 1).unwrap();
+        let facet4 = Facet::new(&tds, cell, 2).unwrap();
 
+        // Facets of the same cell are ordered by the index of the opposite
+        // vertex
         assert!(facet1 < facet3);
         assert!(facet2 < facet3);
         assert!(facet3 > facet1);
         assert!(facet3 > facet2);
-        assert!(facet3 > facet4);
+        assert!(facet3 < facet4);
     }
 }
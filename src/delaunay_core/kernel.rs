@@ -0,0 +1,1053 @@
+//! Geometric kernels, which compute the predicates and constructions used
+//! by [Cell](super::cell::Cell)s and the [Tds](super::triangulation_data_structure::Tds).
+//!
+//! As in [CGAL](https://doc.cgal.org/latest/Kernel_23/index.html), the
+//! geometry is separated from the combinatorics so that the arithmetic can
+//! be chosen per use case:
+//!
+//! * [FastKernel] evaluates everything with plain `f64` arithmetic. It is the
+//!   fastest, but its predicates may give wrong or inconsistent answers for
+//!   nearly degenerate inputs.
+//! * [FilteredKernel] evaluates the predicates with interval arithmetic and
+//!   falls back to exact arithmetic only when the sign is uncertain, as
+//!   per the [predicates](super::predicates) module. Its predicates are
+//!   exact at nearly the speed of [FastKernel], so it is the default.
+//! * [ExactKernel] evaluates everything with arbitrary precision rational
+//!   arithmetic. Its predicates are exact, and its constructions are exact
+//!   until they are rounded.
+//!
+//! The predicates on `f32` coordinates are computed in `f64`, in which they
+//! are exact, and the constructions on them are rounded back to `f32`.
+
+use super::{
+    matrix::{determinant, lifted_determinant, solve},
+    point::Point,
+    predicates::{affinely_independent, insphere, lifted_orient, orient, perturb, perturb_lifted},
+};
+use crate::Coord;
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_rational::{BigRational, Ratio};
+use num_traits::{ToPrimitive, Zero};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::{cmp::Ordering, fmt::Debug, hash::Hash};
+
+/// The [CoordinateScalar] trait is implemented by the types of coordinates
+/// on which a [Kernel] can compute.
+///
+/// Besides floating-point numbers, integers, [Ratio]nals of integers and
+/// arbitrary precision [BigInt]s and [BigRational]s are supported. The
+/// predicates of the exact kernels are computed on their exact values with
+/// arbitrary precision, so there is no overflow or lossy conversion to
+/// `f64`. Coordinates need not be [Copy], and are taken by reference so
+/// that arbitrary precision numbers are not cloned to be converted.
+pub trait CoordinateScalar: Clone + Debug + Default + PartialEq + PartialOrd {
+    /// The floating-point type of constructions, such as circumcenters, on
+    /// these coordinates.
+    type Float: CoordinateScalar + Copy;
+
+    /// Returns the nearest `f64` to the value.
+    fn to_f64(&self) -> f64;
+
+    /// Returns the nearest [CoordinateScalar::Float] to an `f64`.
+    fn float_from_f64(value: f64) -> Self::Float;
+
+    /// Returns the value as an `f64` if it is exactly representable, or if
+    /// it is not finite.
+    fn to_exact_f64(&self) -> Option<f64>;
+
+    /// Returns the exact value as a rational number, or `None` if it is not
+    /// finite.
+    fn to_rational(&self) -> Option<BigRational>;
+}
+
+impl CoordinateScalar for f32 {
+    type Float = f32;
+
+    fn to_f64(&self) -> f64 {
+        f64::from(*self)
+    }
+
+    fn float_from_f64(value: f64) -> f32 {
+        value as f32
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        BigRational::from_float(*self)
+    }
+}
+
+impl CoordinateScalar for f64 {
+    type Float = f64;
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn float_from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        Some(*self)
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        BigRational::from_float(*self)
+    }
+}
+
+impl CoordinateScalar for i32 {
+    type Float = f64;
+
+    fn to_f64(&self) -> f64 {
+        f64::from(*self)
+    }
+
+    fn float_from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        Some(f64::from(*self))
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        Some(BigRational::from_integer(BigInt::from(*self)))
+    }
+}
+
+impl CoordinateScalar for i64 {
+    type Float = f64;
+
+    fn to_f64(&self) -> f64 {
+        *self as f64
+    }
+
+    fn float_from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        // Integers with at most 53 significant bits are exact
+        (self.unsigned_abs() <= 1 << f64::MANTISSA_DIGITS).then_some(*self as f64)
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        Some(BigRational::from_integer(BigInt::from(*self)))
+    }
+}
+
+impl CoordinateScalar for BigInt {
+    type Float = f64;
+
+    fn to_f64(&self) -> f64 {
+        ToPrimitive::to_f64(self).unwrap_or(f64::NAN)
+    }
+
+    fn float_from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        // Integers with at most 53 significant bits are exact
+        (self.bits() <= u64::from(f64::MANTISSA_DIGITS)).then(|| CoordinateScalar::to_f64(self))
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        Some(BigRational::from_integer(self.clone()))
+    }
+}
+
+impl<I> CoordinateScalar for Ratio<I>
+where
+    I: Clone + Debug + Default + Integer + Into<BigInt> + ToPrimitive,
+{
+    type Float = f64;
+
+    fn to_f64(&self) -> f64 {
+        self.to_rational()
+            .and_then(|r| ToPrimitive::to_f64(&r))
+            .unwrap_or(f64::NAN)
+    }
+
+    fn float_from_f64(value: f64) -> f64 {
+        value
+    }
+
+    fn to_exact_f64(&self) -> Option<f64> {
+        if !self.is_integer() {
+            return None;
+        }
+        let numer: BigInt = self.numer().clone().into();
+        numer.to_exact_f64()
+    }
+
+    fn to_rational(&self) -> Option<BigRational> {
+        Some(BigRational::new(
+            self.numer().clone().into(),
+            self.denom().clone().into(),
+        ))
+    }
+}
+
+/// The [Kernel] trait provides the geometric predicates and constructions
+/// on points with coordinates of type `T` in D-dimensional space.
+///
+/// Kernels are stateless types, so they are given as a type parameter of
+/// a [Cell](super::cell::Cell) or [Tds](super::triangulation_data_structure::Tds).
+pub trait Kernel<T, const D: usize>:
+    Clone + Copy + Debug + Default + Eq + Hash + PartialEq + PartialOrd
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    /// The `orientation` function returns the orientation of D + 1 points,
+    /// i.e. the sign of the determinant
+    ///
+    /// ```text
+    /// | p_0 - p_D     |
+    /// | ...           |
+    /// | p_{D-1} - p_D |
+    /// ```
+    ///
+    /// # Arguments:
+    ///
+    /// * `points`: The D + 1 points.
+    ///
+    /// # Returns:
+    ///
+    /// [Ordering::Greater] if the points are positively oriented,
+    /// [Ordering::Less] if they are negatively oriented, and
+    /// [Ordering::Equal] if they are affinely dependent or a coordinate is
+    /// not finite.
+    fn orientation(points: &[Point<T, D>]) -> Ordering;
+
+    /// The `insphere` function checks if a point is inside the circumsphere
+    /// of a D-simplex, independently of the order of its vertices.
+    ///
+    /// # Arguments:
+    ///
+    /// * `simplex`: The D + 1 vertices of the simplex.
+    /// * `point`: The point to check.
+    ///
+    /// # Returns:
+    ///
+    /// [Ordering::Greater] if the point is strictly inside the
+    /// circumsphere, [Ordering::Less] if it is strictly outside, and
+    /// [Ordering::Equal] if it is on the circumsphere, the simplex is
+    /// degenerate, or a coordinate is not finite.
+    fn insphere(simplex: &[Point<T, D>], point: &Point<T, D>) -> Ordering;
+
+    /// The `perturbed_insphere` function is the [Kernel::insphere]
+    /// predicate with ties broken by symbolic perturbation, as per
+    /// [perturbed_insphere](super::predicates::perturbed_insphere), using
+    /// the lexicographic order of the [Point]s.
+    ///
+    /// # Returns:
+    ///
+    /// [Ordering::Greater] if the point is inside the perturbed
+    /// circumsphere, [Ordering::Less] if it is outside, and
+    /// [Ordering::Equal] only if it is a vertex of the simplex or the
+    /// simplex is degenerate.
+    fn perturbed_insphere(simplex: &[Point<T, D>], point: &Point<T, D>) -> Ordering {
+        perturb(
+            simplex,
+            point,
+            Self::insphere(simplex, point),
+            Self::orientation,
+            |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        )
+    }
+
+    /// The `lifted_orientation` function returns the orientation of D + 2
+    /// points lifted onto the paraboloid in (D + 1)-dimensional space, as
+    /// per [lifted_orient].
+    ///
+    /// # Arguments:
+    ///
+    /// * `points`: The D + 2 points.
+    ///
+    /// # Returns:
+    ///
+    /// [Ordering::Greater] if the lifted points are positively oriented,
+    /// [Ordering::Less] if they are negatively oriented, and
+    /// [Ordering::Equal] if they are affinely dependent or a coordinate is
+    /// not finite.
+    fn lifted_orientation(points: &[Point<T, D>]) -> Ordering;
+
+    /// The `perturbed_lifted_orientation` function is the
+    /// [Kernel::lifted_orientation] predicate with ties broken by symbolic
+    /// perturbation, as per
+    /// [perturbed_lifted_orient](super::predicates::perturbed_lifted_orient),
+    /// consistently with [Kernel::perturbed_insphere].
+    ///
+    /// # Returns:
+    ///
+    /// [Ordering::Greater] if the perturbed lifted points are positively
+    /// oriented, [Ordering::Less] if they are negatively oriented, and
+    /// [Ordering::Equal] only if two points coincide or the points lie on a
+    /// hyperplane.
+    fn perturbed_lifted_orientation(points: &[Point<T, D>]) -> Ordering {
+        perturb_lifted(
+            points,
+            Self::lifted_orientation(points),
+            Self::orientation,
+            |a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal),
+        )
+    }
+
+    /// The `affinely_independent` function checks if points are affinely
+    /// independent, i.e. if the vectors from the first point to the others
+    /// are linearly independent. By default, their rank is computed exactly.
+    ///
+    /// # Returns:
+    ///
+    /// `true` if the points are affinely independent, and `false` otherwise
+    /// or if a coordinate is not finite.
+    fn affinely_independent(points: &[Point<T, D>]) -> bool {
+        if points.len() > D + 1 {
+            return false;
+        }
+        match rational_rows(&points[1.min(points.len())..], &points[0]) {
+            Some(rows) => rational_rank(rows) == points.len() - 1,
+            None => false,
+        }
+    }
+
+    /// The `circumcenter` function returns the center of the circumsphere
+    /// of a D-simplex.
+    ///
+    /// # Arguments:
+    ///
+    /// * `simplex`: The D + 1 vertices of the simplex.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the circumcenter, or an error if the simplex
+    /// does not have D + 1 vertices or is degenerate. It is computed in
+    /// `f64` and rounded to the [CoordinateScalar::Float] type.
+    fn circumcenter(simplex: &[Point<T, D>]) -> Result<Point<T::Float, D>, anyhow::Error>
+    where
+        [T::Float; D]: Coord;
+
+    /// The `squared_distance` function returns the squared Euclidean
+    /// distance between two points, computed in `f64` and rounded to the
+    /// [CoordinateScalar::Float] type.
+    fn squared_distance(a: &Point<T, D>, b: &Point<T, D>) -> T::Float;
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+/// The [FastKernel] computes with plain `f64` arithmetic, which is fast but
+/// not robust for nearly degenerate inputs.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::kernel::{FastKernel, Kernel};
+/// use dd_delaunay::delaunay_core::point::Point;
+/// use std::cmp::Ordering;
+/// let simplex = [Point::new([1.0, 0.0]), Point::new([0.0, 1.0]), Point::new([-1.0, 0.0])];
+/// assert_eq!(FastKernel::orientation(&simplex), Ordering::Greater);
+/// assert_eq!(FastKernel::insphere(&simplex, &Point::new([0.5, 0.5])), Ordering::Greater);
+/// assert_eq!(FastKernel::circumcenter(&simplex).unwrap(), Point::new([0.0, 0.0]));
+/// ```
+pub struct FastKernel;
+
+impl<T, const D: usize> Kernel<T, D> for FastKernel
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    fn orientation(points: &[Point<T, D>]) -> Ordering {
+        assert_eq!(points.len(), D + 1, "Orientation needs D + 1 points!");
+        let rows: [[f64; D]; D] = std::array::from_fn(|i| {
+            std::array::from_fn(|j| points[i].coords[j].to_f64() - points[D].coords[j].to_f64())
+        });
+
+        determinant(rows)
+            .partial_cmp(&0.0)
+            .unwrap_or(Ordering::Equal)
+    }
+
+    fn insphere(simplex: &[Point<T, D>], point: &Point<T, D>) -> Ordering {
+        let orientation = Self::orientation(simplex);
+        let lifted = lifted_determinant(
+            &float_points(simplex),
+            &point.coords.each_ref().map(T::to_f64),
+        )
+        .partial_cmp(&0.0)
+        .unwrap_or(Ordering::Equal);
+
+        match orientation {
+            Ordering::Greater => lifted,
+            Ordering::Less => lifted.reverse(),
+            Ordering::Equal => Ordering::Equal,
+        }
+    }
+
+    fn lifted_orientation(points: &[Point<T, D>]) -> Ordering {
+        assert_eq!(
+            points.len(),
+            D + 2,
+            "Lifted orientation needs D + 2 points!"
+        );
+        lifted_determinant(
+            &float_points(&points[..=D]),
+            &points[D + 1].coords.each_ref().map(T::to_f64),
+        )
+        .partial_cmp(&0.0)
+        .unwrap_or(Ordering::Equal)
+    }
+
+    fn circumcenter(simplex: &[Point<T, D>]) -> Result<Point<T::Float, D>, anyhow::Error>
+    where
+        [T::Float; D]: Coord,
+    {
+        float_circumcenter(simplex)
+    }
+
+    fn squared_distance(a: &Point<T, D>, b: &Point<T, D>) -> T::Float {
+        float_squared_distance(a, b)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+/// The [FilteredKernel] computes the predicates exactly with the robust
+/// [orient] and [insphere] predicates, which only fall back to exact
+/// arithmetic when interval arithmetic cannot certify the sign. Points
+/// with coordinates which are not exactly `f64`s, such as large integers
+/// or fractions, are handled by the [ExactKernel] instead. The
+/// constructions use `f64` arithmetic.
+///
+/// This is the default [Kernel].
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::kernel::{FilteredKernel, Kernel};
+/// use dd_delaunay::delaunay_core::point::Point;
+/// use std::cmp::Ordering;
+/// let simplex = [Point::new([1.0, 0.0]), Point::new([0.0, 1.0]), Point::new([-1.0, 0.0])];
+/// assert_eq!(FilteredKernel::insphere(&simplex, &Point::new([0.0, -1.0])), Ordering::Equal);
+/// assert_eq!(FilteredKernel::perturbed_insphere(&simplex, &Point::new([0.0, -1.0])), Ordering::Greater);
+/// ```
+pub struct FilteredKernel;
+
+impl<T, const D: usize> Kernel<T, D> for FilteredKernel
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    fn orientation(points: &[Point<T, D>]) -> Ordering {
+        match exact_float_points(points) {
+            Some(points) => orient(&points),
+            None => <ExactKernel as Kernel<T, D>>::orientation(points),
+        }
+    }
+
+    fn insphere(simplex: &[Point<T, D>], point: &Point<T, D>) -> Ordering {
+        match (
+            exact_float_points(simplex),
+            exact_float_points(std::slice::from_ref(point)),
+        ) {
+            (Some(simplex), Some(point)) => insphere(&simplex, &point[0]),
+            _ => <ExactKernel as Kernel<T, D>>::insphere(simplex, point),
+        }
+    }
+
+    fn lifted_orientation(points: &[Point<T, D>]) -> Ordering {
+        match exact_float_points(points) {
+            Some(points) => lifted_orient(&points),
+            None => <ExactKernel as Kernel<T, D>>::lifted_orientation(points),
+        }
+    }
+
+    fn affinely_independent(points: &[Point<T, D>]) -> bool {
+        match exact_float_points(points) {
+            Some(points) => affinely_independent(&points),
+            None => <ExactKernel as Kernel<T, D>>::affinely_independent(points),
+        }
+    }
+
+    fn circumcenter(simplex: &[Point<T, D>]) -> Result<Point<T::Float, D>, anyhow::Error>
+    where
+        [T::Float; D]: Coord,
+    {
+        float_circumcenter(simplex)
+    }
+
+    fn squared_distance(a: &Point<T, D>, b: &Point<T, D>) -> T::Float {
+        float_squared_distance(a, b)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize))]
+/// The [ExactKernel] computes everything with arbitrary precision rational
+/// arithmetic. The predicates are exact, and the constructions are exact
+/// until they are rounded to the nearest `f64`, and then to the
+/// [CoordinateScalar::Float] type.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::kernel::{ExactKernel, Kernel};
+/// use dd_delaunay::delaunay_core::point::Point;
+/// use std::cmp::Ordering;
+/// let simplex = [Point::new([0.5 + f64::EPSILON, 0.5]), Point::new([12.0, 12.0]), Point::new([24.0, 24.0])];
+/// assert_eq!(ExactKernel::orientation(&simplex), Ordering::Less);
+/// let simplex = [Point::new([0.0, 0.0]), Point::new([2.0, 0.0]), Point::new([0.0, 2.0])];
+/// assert_eq!(ExactKernel::circumcenter(&simplex).unwrap(), Point::new([1.0, 1.0]));
+/// ```
+pub struct ExactKernel;
+
+impl<T, const D: usize> Kernel<T, D> for ExactKernel
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    fn orientation(points: &[Point<T, D>]) -> Ordering {
+        assert_eq!(points.len(), D + 1, "Orientation needs D + 1 points!");
+        match rational_rows(&points[..D], &points[D]) {
+            Some(rows) => rational_det_sign(rows),
+            None => Ordering::Equal,
+        }
+    }
+
+    fn insphere(simplex: &[Point<T, D>], point: &Point<T, D>) -> Ordering {
+        let orientation = Self::orientation(simplex);
+        let Some(mut rows) = rational_rows(simplex, point) else {
+            return Ordering::Equal;
+        };
+        for row in rows.iter_mut() {
+            let lift = row.iter().map(|c| c * c).sum();
+            row.push(lift);
+        }
+        let lifted = rational_det_sign(rows);
+
+        match orientation {
+            Ordering::Greater => lifted,
+            Ordering::Less => lifted.reverse(),
+            Ordering::Equal => Ordering::Equal,
+        }
+    }
+
+    fn lifted_orientation(points: &[Point<T, D>]) -> Ordering {
+        assert_eq!(
+            points.len(),
+            D + 2,
+            "Lifted orientation needs D + 2 points!"
+        );
+        let Some(mut rows) = rational_rows(&points[..=D], &points[D + 1]) else {
+            return Ordering::Equal;
+        };
+        for row in rows.iter_mut() {
+            let lift = row.iter().map(|c| c * c).sum();
+            row.push(lift);
+        }
+
+        rational_det_sign(rows)
+    }
+
+    fn circumcenter(simplex: &[Point<T, D>]) -> Result<Point<T::Float, D>, anyhow::Error>
+    where
+        [T::Float; D]: Coord,
+    {
+        if simplex.len() != D + 1 {
+            return Err(anyhow::Error::msg("Not a simplex!"));
+        }
+        let origin = &simplex[0];
+        let rows = rational_rows(&simplex[1..], origin)
+            .ok_or_else(|| anyhow::Error::msg("Coordinates are not finite!"))?;
+
+        // The circumcenter relative to the first vertex is equidistant from
+        // the origin and the other vertices relative to it
+        let half = BigRational::new(BigInt::from(1), BigInt::from(2));
+        let system = rows
+            .into_iter()
+            .map(|mut row| {
+                let rhs = row.iter().map(|c| c * c).sum::<BigRational>() * &half;
+                row.push(rhs);
+                row
+            })
+            .collect();
+        let solution =
+            rational_solve(system).ok_or_else(|| anyhow::Error::msg("Degenerate simplex!"))?;
+
+        let mut center = [T::Float::default(); D];
+        for (j, c) in center.iter_mut().enumerate() {
+            let exact = &solution[j] + origin.coords[j].to_rational().unwrap_or_default();
+            *c = T::float_from_f64(ToPrimitive::to_f64(&exact).unwrap_or(f64::NAN));
+        }
+
+        Ok(Point::new(center))
+    }
+
+    fn squared_distance(a: &Point<T, D>, b: &Point<T, D>) -> T::Float {
+        T::float_from_f64(
+            rational_rows(std::slice::from_ref(a), b)
+                .and_then(|rows| {
+                    ToPrimitive::to_f64(&rows[0].iter().map(|c| c * c).sum::<BigRational>())
+                })
+                .unwrap_or(f64::NAN),
+        )
+    }
+}
+
+/// Converts [Point]s to arrays of `f64`s.
+fn float_points<T, const D: usize>(points: &[Point<T, D>]) -> Vec<[f64; D]>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    points
+        .iter()
+        .map(|p| p.coords.each_ref().map(T::to_f64))
+        .collect()
+}
+
+/// Converts [Point]s to arrays of `f64`s if all their coordinates are
+/// exactly representable.
+fn exact_float_points<T, const D: usize>(points: &[Point<T, D>]) -> Option<Vec<[f64; D]>>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    points
+        .iter()
+        .map(|p| {
+            let mut coords = [0.0; D];
+            for (c, coord) in coords.iter_mut().zip(p.coords.iter()) {
+                *c = coord.to_exact_f64()?;
+            }
+            Some(coords)
+        })
+        .collect()
+}
+
+/// Returns the circumcenter of a D-simplex computed in `f64`, by solving
+/// the system of the perpendicular bisectors of the edges from its first
+/// vertex
+///
+/// ```text
+/// 2 (x_i - x_0) . (C - x_0) = |x_i - x_0|^2
+/// ```
+///
+/// with LU decomposition on the stack.
+fn float_circumcenter<T, const D: usize>(
+    simplex: &[Point<T, D>],
+) -> Result<Point<T::Float, D>, anyhow::Error>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+    [T::Float; D]: Coord,
+{
+    if simplex.len() != D + 1 {
+        return Err(anyhow::Error::msg("Not a simplex!"));
+    }
+    let origin = simplex[0].coords.each_ref().map(T::to_f64);
+    let rows: [[f64; D]; D] = std::array::from_fn(|i| {
+        std::array::from_fn(|j| simplex[i + 1].coords[j].to_f64() - origin[j])
+    });
+    let rhs = rows.map(|row| row.iter().map(|c| c * c).sum::<f64>() * 0.5);
+    let center = solve(rows, rhs).ok_or_else(|| anyhow::Error::msg("Degenerate simplex!"))?;
+
+    Ok(Point::new(std::array::from_fn(|j| {
+        T::float_from_f64(center[j] + origin[j])
+    })))
+}
+
+/// Returns the squared distance between two [Point]s computed in `f64`.
+fn float_squared_distance<T, const D: usize>(a: &Point<T, D>, b: &Point<T, D>) -> T::Float
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    let squared_distance = a
+        .coords
+        .iter()
+        .zip(b.coords.iter())
+        .map(|(x, y)| (x.to_f64() - y.to_f64()).powi(2))
+        .sum();
+
+    T::float_from_f64(squared_distance)
+}
+
+/// Returns the exact coordinates of [Point]s relative to an origin, or
+/// `None` if a coordinate is not finite.
+fn rational_rows<T, const D: usize>(
+    points: &[Point<T, D>],
+    origin: &Point<T, D>,
+) -> Option<Vec<Vec<BigRational>>>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    let origin: Vec<BigRational> = origin
+        .coords
+        .iter()
+        .map(|c| c.to_rational())
+        .collect::<Option<_>>()?;
+    points
+        .iter()
+        .map(|p| {
+            p.coords
+                .iter()
+                .zip(origin.iter())
+                .map(|(c, o)| Some(c.to_rational()? - o))
+                .collect()
+        })
+        .collect()
+}
+
+/// Reduces a matrix of rational numbers to row echelon form, returning its
+/// rank and whether an odd number of rows were swapped.
+fn rational_echelon(matrix: &mut [Vec<BigRational>]) -> (usize, bool) {
+    let columns = matrix.first().map_or(0, |row| row.len());
+    let mut rank = 0;
+    let mut swapped = false;
+    for column in 0..columns {
+        let Some(pivot) = (rank..matrix.len()).find(|i| !matrix[*i][column].is_zero()) else {
+            continue;
+        };
+        if pivot != rank {
+            matrix.swap(pivot, rank);
+            swapped = !swapped;
+        }
+
+        let (pivot_rows, rows) = matrix.split_at_mut(rank + 1);
+        let pivot_row = &pivot_rows[rank];
+        for row in rows.iter_mut() {
+            let factor = &row[column] / &pivot_row[column];
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row.iter()).skip(column) {
+                *entry -= &factor * pivot_entry;
+            }
+        }
+        rank += 1;
+    }
+
+    (rank, swapped)
+}
+
+/// Returns the rank of a matrix of rational numbers.
+fn rational_rank(mut matrix: Vec<Vec<BigRational>>) -> usize {
+    rational_echelon(&mut matrix).0
+}
+
+/// Returns the sign of the determinant of a square matrix of rational
+/// numbers.
+fn rational_det_sign(mut matrix: Vec<Vec<BigRational>>) -> Ordering {
+    let (rank, swapped) = rational_echelon(&mut matrix);
+    if rank < matrix.len() {
+        return Ordering::Equal;
+    }
+
+    let negative = matrix
+        .iter()
+        .enumerate()
+        .filter(|(i, row)| row[*i] < BigRational::zero())
+        .count()
+        % 2
+        == 1;
+    if negative != swapped {
+        Ordering::Less
+    } else {
+        Ordering::Greater
+    }
+}
+
+/// Solves a square system of linear equations over the rational numbers,
+/// given as an augmented matrix, or returns `None` if it is singular.
+fn rational_solve(mut system: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = system.len();
+    let (rank, _) = rational_echelon(&mut system);
+    if rank < n || (0..n).any(|i| system[i][i].is_zero()) {
+        return None;
+    }
+
+    let mut solution = vec![BigRational::zero(); n];
+    for k in (0..n).rev() {
+        let sum: BigRational = (k + 1..n).map(|j| &system[k][j] * &solution[j]).sum();
+        solution[k] = (&system[k][n] - sum) / &system[k][k];
+    }
+
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    fn points<const D: usize>(coords: &[[f64; D]]) -> Vec<Point<f64, D>>
+    where
+        [f64; D]: Coord,
+    {
+        coords.iter().map(|c| Point::new(*c)).collect()
+    }
+
+    #[test]
+    fn kernel_orientation() {
+        let simplex = points(&[
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+        ]);
+        let mut reversed = simplex.clone();
+        reversed.swap(0, 1);
+        let flat = points(&[
+            [0.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [1.0, 1.0, 0.0],
+        ]);
+
+        for (orientation, expected) in [
+            (FastKernel::orientation(&simplex), Ordering::Less),
+            (FilteredKernel::orientation(&simplex), Ordering::Less),
+            (ExactKernel::orientation(&simplex), Ordering::Less),
+            (FastKernel::orientation(&reversed), Ordering::Greater),
+            (FilteredKernel::orientation(&reversed), Ordering::Greater),
+            (ExactKernel::orientation(&reversed), Ordering::Greater),
+            (FastKernel::orientation(&flat), Ordering::Equal),
+            (FilteredKernel::orientation(&flat), Ordering::Equal),
+            (ExactKernel::orientation(&flat), Ordering::Equal),
+        ] {
+            assert_eq!(orientation, expected);
+        }
+    }
+
+    #[test]
+    fn kernel_nearly_collinear() {
+        // The determinant is 12 * (y - x) * 2^-52, which plain floating-point
+        // evaluation gets wrong for some of these points
+        let ulp = f64::EPSILON;
+        let mut fast_errors = 0;
+        for x in 0..32 {
+            for y in 0..32 {
+                let simplex = points(&[
+                    [0.5 + x as f64 * ulp, 0.5 + y as f64 * ulp],
+                    [12.0, 12.0],
+                    [24.0, 24.0],
+                ]);
+                let expected = y.cmp(&x);
+                assert_eq!(FilteredKernel::orientation(&simplex), expected);
+                assert_eq!(ExactKernel::orientation(&simplex), expected);
+                if FastKernel::orientation(&simplex) != expected {
+                    fast_errors += 1;
+                }
+            }
+        }
+
+        assert!(fast_errors > 0);
+    }
+
+    #[test]
+    fn kernel_insphere() {
+        let simplex = points(&[[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]]);
+        let cases = [
+            ([0.5, 0.5], Ordering::Greater),
+            ([0.0, -1.0], Ordering::Equal),
+            ([2.0, 0.0], Ordering::Less),
+        ];
+
+        for (point, expected) in cases {
+            let point = Point::new(point);
+            assert_eq!(FastKernel::insphere(&simplex, &point), expected);
+            assert_eq!(FilteredKernel::insphere(&simplex, &point), expected);
+            assert_eq!(ExactKernel::insphere(&simplex, &point), expected);
+        }
+    }
+
+    #[test]
+    fn kernel_perturbed_insphere() {
+        let simplex = points(&[[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]]);
+        let cospherical = Point::new([0.0, -1.0]);
+        let expected = perturbed_insphere_f64(&simplex, &cospherical);
+
+        assert_ne!(expected, Ordering::Equal);
+        assert_eq!(
+            FastKernel::perturbed_insphere(&simplex, &cospherical),
+            expected
+        );
+        assert_eq!(
+            FilteredKernel::perturbed_insphere(&simplex, &cospherical),
+            expected
+        );
+        assert_eq!(
+            ExactKernel::perturbed_insphere(&simplex, &cospherical),
+            expected
+        );
+        assert_eq!(
+            ExactKernel::perturbed_insphere(&simplex, &simplex[1]),
+            Ordering::Equal
+        );
+    }
+
+    fn perturbed_insphere_f64<const D: usize>(
+        simplex: &[Point<f64, D>],
+        point: &Point<f64, D>,
+    ) -> Ordering
+    where
+        [f64; D]: Coord,
+    {
+        super::super::predicates::perturbed_insphere(&float_points(simplex), &point.coords)
+    }
+
+    #[test]
+    fn kernel_lifted_orientation() {
+        let square = points(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]);
+        let cases = [
+            ([0.5, 0.5], Ordering::Greater),
+            ([1.0, 1.0], Ordering::Equal),
+            ([2.0, 0.0], Ordering::Less),
+        ];
+
+        for (point, expected) in cases {
+            let lifted = [square[0], square[1], square[3], Point::new(point)];
+            assert_eq!(FastKernel::lifted_orientation(&lifted), expected);
+            assert_eq!(FilteredKernel::lifted_orientation(&lifted), expected);
+            assert_eq!(ExactKernel::lifted_orientation(&lifted), expected);
+        }
+
+        // The perturbation breaks ties as for the insphere predicate
+        let expected = super::super::predicates::perturbed_lifted_orient(&float_points(&square));
+        assert_ne!(expected, Ordering::Equal);
+        assert_eq!(FastKernel::perturbed_lifted_orientation(&square), expected);
+        assert_eq!(
+            FilteredKernel::perturbed_lifted_orientation(&square),
+            expected
+        );
+        assert_eq!(ExactKernel::perturbed_lifted_orientation(&square), expected);
+        let square = [square[0], square[1], square[2], square[1]];
+        assert_eq!(
+            ExactKernel::perturbed_lifted_orientation(&square),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn kernel_affinely_independent() {
+        let line = points(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]);
+        let triangle = points(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]);
+
+        assert!(!FastKernel::affinely_independent(&line));
+        assert!(!FilteredKernel::affinely_independent(&line));
+        assert!(!ExactKernel::affinely_independent(&line));
+        assert!(FastKernel::affinely_independent(&triangle));
+        assert!(FilteredKernel::affinely_independent(&triangle));
+        assert!(ExactKernel::affinely_independent(&triangle));
+        assert!(ExactKernel::affinely_independent(&triangle[..1]));
+    }
+
+    #[test]
+    fn kernel_circumcenter() {
+        let simplex = points(&[
+            [1.0, 1.0, 1.0],
+            [2.0, 1.0, 1.0],
+            [1.0, 2.0, 1.0],
+            [1.0, 1.0, 2.0],
+        ]);
+        let expected = Point::new([1.5, 1.5, 1.5]);
+
+        assert_eq!(FastKernel::circumcenter(&simplex).unwrap(), expected);
+        assert_eq!(FilteredKernel::circumcenter(&simplex).unwrap(), expected);
+        assert_eq!(ExactKernel::circumcenter(&simplex).unwrap(), expected);
+
+        let flat = points(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+        assert!(FastKernel::circumcenter(&flat).is_err());
+        assert!(ExactKernel::circumcenter(&flat).is_err());
+        assert!(ExactKernel::circumcenter(&flat[..2]).is_err());
+    }
+
+    #[test]
+    fn kernel_squared_distance() {
+        let a = Point::new([1.0, 2.0, 3.0]);
+        let b = Point::new([4.0, 6.0, 3.0]);
+
+        assert_eq!(FastKernel::squared_distance(&a, &b), 25.0);
+        assert_eq!(FilteredKernel::squared_distance(&a, &b), 25.0);
+        assert_eq!(ExactKernel::squared_distance(&a, &b), 25.0);
+    }
+
+    #[test]
+    fn kernel_f32() {
+        let simplex: Vec<Point<f32, 2>> = vec![
+            Point::new([0.0, 0.0]),
+            Point::new([2.0, 0.0]),
+            Point::new([0.0, 2.0]),
+        ];
+
+        assert_eq!(FilteredKernel::orientation(&simplex), Ordering::Greater);
+        assert_eq!(
+            ExactKernel::insphere(&simplex, &Point::new([1.0, 1.0])),
+            Ordering::Greater
+        );
+        assert_eq!(
+            FilteredKernel::circumcenter(&simplex).unwrap(),
+            Point::new([1.0, 1.0])
+        );
+    }
+
+    #[test]
+    fn kernel_integers() {
+        // The coordinates are not exactly f64s, which would make the points
+        // collinear
+        let n = 1_i64 << 60;
+        let simplex: Vec<Point<i64, 2>> = vec![
+            Point::new([0, 0]),
+            Point::new([n, n + 1]),
+            Point::new([n + 1, n + 2]),
+        ];
+
+        assert_eq!(FilteredKernel::orientation(&simplex), Ordering::Less);
+        assert_eq!(ExactKernel::orientation(&simplex), Ordering::Less);
+        assert_eq!(
+            orient(&float_points(&simplex)),
+            Ordering::Equal,
+            "The points are collinear in f64"
+        );
+        assert!(FilteredKernel::affinely_independent(&simplex));
+        assert_eq!(1_i64.to_exact_f64(), Some(1.0));
+        assert_eq!((n + 1).to_exact_f64(), None);
+    }
+
+    #[test]
+    fn kernel_rationals() {
+        let third = Ratio::new(1, 3);
+        let zero = Ratio::from_integer(0);
+        let simplex: Vec<Point<Ratio<i64>, 2>> = vec![
+            Point::new([third, zero]),
+            Point::new([zero, third]),
+            Point::new([zero, zero]),
+        ];
+
+        // The point is exactly on the circumcircle
+        let point = Point::new([third, third]);
+        assert_eq!(FilteredKernel::insphere(&simplex, &point), Ordering::Equal);
+        assert_eq!(ExactKernel::insphere(&simplex, &point), Ordering::Equal);
+        assert_ne!(
+            FilteredKernel::perturbed_insphere(&simplex, &point),
+            Ordering::Equal
+        );
+        assert_eq!(
+            ExactKernel::circumcenter(&simplex).unwrap(),
+            Point::new([1.0 / 6.0, 1.0 / 6.0])
+        );
+        assert_eq!(
+            ExactKernel::squared_distance(&simplex[0], &simplex[1]),
+            2.0 / 9.0
+        );
+        assert_eq!(Ratio::new(4_i64, 2).to_exact_f64(), Some(2.0));
+        assert_eq!(third.to_exact_f64(), None);
+    }
+}
@@ -1,4 +1,11 @@
 //! Matrix operations.
+//!
+//! Besides [invert], which works on heap allocated [Matrix]es of any size,
+//! the [determinant], [solve] and [lifted_determinant] functions work on
+//! fixed-size arrays of `f64`s, which are stored on the stack since their
+//! dimension is a const generic. They are used by the predicates and
+//! constructions of the [kernels](super::kernel), and are specialized for
+//! small dimensions.
 
 use anyhow::Result;
 use peroxide::prelude::*;
@@ -39,6 +46,182 @@ pub fn invert(matrix: &Matrix) -> Result<Matrix, anyhow::Error> {
     })
 }
 
+/// Returns the determinant of a D x D matrix, given by its rows.
+///
+/// Matrices of up to 3 dimensions use the closed form cofactor expansion,
+/// and larger ones LU decomposition with partial pivoting, all on the stack.
+///
+/// # Example
+///
+/// ```
+/// use dd_delaunay::delaunay_core::matrix::determinant;
+///
+/// assert_eq!(determinant([[1.0, 2.0], [3.0, 4.0]]), -2.0);
+/// assert_eq!(determinant([[2.0, 0.0, 0.0, 0.0], [0.0, 3.0, 0.0, 0.0], [0.0, 0.0, 4.0, 0.0], [0.0, 0.0, 0.0, 5.0]]), 120.0);
+/// ```
+pub fn determinant<const D: usize>(mut matrix: [[f64; D]; D]) -> f64 {
+    let m = &matrix;
+    match D {
+        0 => 1.0,
+        1 => m[0][0],
+        2 => m[0][0] * m[1][1] - m[0][1] * m[1][0],
+        3 => {
+            m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+                - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+                + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+        }
+        _ => {
+            let mut permutation = [0; D];
+            match lu_decompose(&mut matrix, &mut permutation) {
+                Some(odd) => {
+                    let det: f64 = (0..D).map(|k| matrix[k][k]).product();
+                    if odd {
+                        -det
+                    } else {
+                        det
+                    }
+                }
+                None => 0.0,
+            }
+        }
+    }
+}
+
+/// Solves the D x D linear system `matrix * x = rhs` with LU decomposition
+/// with partial pivoting, on the stack.
+///
+/// # Returns
+///
+/// The solution `x`, or `None` if the matrix is singular or a pivot is not
+/// finite.
+///
+/// # Example
+///
+/// ```
+/// use dd_delaunay::delaunay_core::matrix::solve;
+///
+/// assert_eq!(solve([[2.0, 0.0], [0.0, 4.0]], [1.0, 1.0]), Some([0.5, 0.25]));
+/// assert_eq!(solve([[1.0, 2.0], [2.0, 4.0]], [1.0, 1.0]), None);
+/// ```
+pub fn solve<const D: usize>(mut matrix: [[f64; D]; D], rhs: [f64; D]) -> Option<[f64; D]> {
+    let mut permutation = [0; D];
+    lu_decompose(&mut matrix, &mut permutation)?;
+
+    // Forward substitution with the unit lower triangle, then back
+    // substitution with the upper triangle
+    let mut x = [0.0; D];
+    for i in 0..D {
+        let sum: f64 = (0..i).map(|j| matrix[i][j] * x[j]).sum();
+        x[i] = rhs[permutation[i]] - sum;
+    }
+    for i in (0..D).rev() {
+        let sum: f64 = (i + 1..D).map(|j| matrix[i][j] * x[j]).sum();
+        x[i] = (x[i] - sum) / matrix[i][i];
+    }
+
+    Some(x)
+}
+
+/// Returns the determinant of the (D + 1) x (D + 1) matrix whose rows are
+/// the D + 1 `points` relative to the `origin`, lifted onto the paraboloid
+///
+/// ```text
+/// | x_0 - p    |x_0 - p|^2 |
+/// | ...        ...         |
+/// | x_D - p    |x_D - p|^2 |
+/// ```
+///
+/// as used by the insphere predicate. The row with the largest lift is
+/// eliminated, leaving its Schur complement, a D x D matrix whose
+/// [determinant] is computed on the stack.
+///
+/// # Example
+///
+/// ```
+/// use dd_delaunay::delaunay_core::matrix::lifted_determinant;
+///
+/// let simplex = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]];
+/// assert!(lifted_determinant(&simplex, &[0.0, 0.5]) > 0.0);
+/// assert_eq!(lifted_determinant(&simplex, &[0.0, -1.0]), 0.0);
+/// ```
+pub fn lifted_determinant<const D: usize>(points: &[[f64; D]], origin: &[f64; D]) -> f64 {
+    assert_eq!(
+        points.len(),
+        D + 1,
+        "Lifted determinant needs D + 1 points!"
+    );
+    let row = |i: usize| -> [f64; D] { std::array::from_fn(|j| points[i][j] - origin[j]) };
+    let lift = |row: &[f64; D]| -> f64 { row.iter().map(|c| c * c).sum() };
+
+    let pivot = (0..=D)
+        .max_by(|a, b| lift(&row(*a)).total_cmp(&lift(&row(*b))))
+        .unwrap_or(D);
+    let pivot_row = row(pivot);
+    let pivot_lift = lift(&pivot_row);
+    if pivot_lift == 0.0 {
+        // Every row is zero
+        return 0.0;
+    }
+
+    let mut complement = [[0.0; D]; D];
+    for (i, entries) in (0..=D).filter(|i| *i != pivot).zip(complement.iter_mut()) {
+        let row = row(i);
+        let factor = lift(&row) / pivot_lift;
+        for (j, entry) in entries.iter_mut().enumerate() {
+            *entry = row[j] - factor * pivot_row[j];
+        }
+    }
+    let det = pivot_lift * determinant(complement);
+
+    // Moving the pivot row last takes D - pivot swaps
+    if (D - pivot) % 2 == 0 {
+        det
+    } else {
+        -det
+    }
+}
+
+/// Factors a square matrix in place into a unit lower triangle and an
+/// upper triangle with partial pivoting, recording the row of the original
+/// matrix in each row of the factors.
+///
+/// Returns whether an odd number of rows were swapped, or `None` if the
+/// matrix is singular or a pivot is not finite.
+fn lu_decompose<const D: usize>(
+    matrix: &mut [[f64; D]; D],
+    permutation: &mut [usize; D],
+) -> Option<bool> {
+    for (i, p) in permutation.iter_mut().enumerate() {
+        *p = i;
+    }
+    let mut odd = false;
+    for k in 0..D {
+        let pivot = (k..D)
+            .max_by(|a, b| matrix[*a][k].abs().total_cmp(&matrix[*b][k].abs()))
+            .unwrap_or(k);
+        if matrix[pivot][k] == 0.0 || !matrix[pivot][k].is_finite() {
+            return None;
+        }
+        if pivot != k {
+            matrix.swap(pivot, k);
+            permutation.swap(pivot, k);
+            odd = !odd;
+        }
+
+        let (pivot_rows, rows) = matrix.split_at_mut(k + 1);
+        let pivot_row = &pivot_rows[k];
+        for row in rows.iter_mut() {
+            let factor = row[k] / pivot_row[k];
+            row[k] = factor;
+            for j in k + 1..D {
+                row[j] -= factor * pivot_row[j];
+            }
+        }
+    }
+
+    Some(odd)
+}
+
 /// Error type for matrix operations.
 #[derive(Debug, Error)]
 pub enum MatrixError {
@@ -141,6 +324,80 @@ mod tests {
             .contains("Matrix is singular"));
     }
 
+    #[test]
+    fn matrix_determinant() {
+        // Compare with peroxide for each specialized size and the general one
+        fn check<const D: usize>(rows: [[f64; D]; D]) {
+            let expected = matrix(rows.iter().flatten().copied().collect(), D, D, Row).det();
+            assert!((determinant(rows) - expected).abs() < 1e-9);
+        }
+
+        check([[3.0]]);
+        check([[1.0, 2.0], [3.0, 4.0]]);
+        check([[2.0, -1.0, 0.5], [1.0, 3.0, 2.0], [0.0, 1.0, 4.0]]);
+        check([
+            [0.0, 2.0, 1.0, 3.0],
+            [1.0, 0.0, 2.0, 1.0],
+            [4.0, 1.0, 0.0, 2.0],
+            [1.0, 3.0, 1.0, 0.0],
+        ]);
+        check([
+            [2.0, 1.0, 0.0, 0.0, 1.0],
+            [1.0, 3.0, 1.0, 0.0, 0.0],
+            [0.0, 1.0, 4.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0, 5.0, 1.0],
+            [1.0, 0.0, 0.0, 1.0, 6.0],
+        ]);
+        assert_eq!(determinant([[1.0, 2.0, 3.0, 4.0]; 4]), 0.0);
+    }
+
+    #[test]
+    fn matrix_solve() {
+        let rows = [
+            [0.0, 2.0, 1.0, 3.0],
+            [1.0, 0.0, 2.0, 1.0],
+            [4.0, 1.0, 0.0, 2.0],
+            [1.0, 3.0, 1.0, 0.0],
+        ];
+        let expected = [1.0, -2.0, 0.5, 3.0];
+        let rhs = rows.map(|row| row.iter().zip(expected).map(|(a, x)| a * x).sum());
+        let solution = solve(rows, rhs).unwrap();
+
+        for (x, e) in solution.iter().zip(expected) {
+            assert!((x - e).abs() < 1e-12);
+        }
+        assert!(solve([[1.0, 2.0, 3.0, 4.0]; 4], [1.0; 4]).is_none());
+        assert!(solve([[f64::NAN]], [1.0]).is_none());
+    }
+
+    #[test]
+    fn matrix_lifted_determinant() {
+        let mut points = [
+            [0.0, 0.0, 0.0],
+            [3.0, 0.5, 0.0],
+            [0.0, 2.0, 1.0],
+            [1.0, 0.0, 4.0],
+        ];
+        let origin = [0.5, 0.25, -1.0];
+
+        // Rotate the rows so that the largest lift is eliminated from each
+        for _ in 0..4 {
+            let data = points
+                .iter()
+                .flat_map(|p| {
+                    let row: Vec<f64> = p.iter().zip(origin).map(|(x, o)| x - o).collect();
+                    let lift = row.iter().map(|c| c * c).sum();
+                    row.into_iter().chain(std::iter::once(lift))
+                })
+                .collect();
+            let expected = matrix(data, 4, 4, Row).det();
+
+            assert!((lifted_determinant(&points, &origin) - expected).abs() < 1e-9);
+            points.rotate_left(1);
+        }
+        assert_eq!(lifted_determinant(&[[1.0, 1.0]; 3], &[1.0, 1.0]), 0.0);
+    }
+
     // #[test]
     // fn matrix_serialization() {
     //     let matrix = matrix(c!(1,2,3,4), 2, 2, Row);
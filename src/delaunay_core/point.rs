@@ -17,7 +17,7 @@ use serde::{Deserialize, Serialize};
 ///   integer known at compile time.
 pub struct Point<T, const DIMS: usize>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     [T; DIMS]: Coord,
 {
     /// The coordinates of the point.
@@ -40,7 +40,7 @@ where
 
 impl<T, const D: usize> Point<T, D>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
     /// The function `new` creates a new instance of a [Point] with the given
@@ -99,9 +99,9 @@ where
     /// ```
     pub fn origin() -> Self
     where
-        T: num_traits::Zero + Copy,
+        T: num_traits::Zero,
     {
-        Self::new([T::zero(); D])
+        Self::new(std::array::from_fn(|_| T::zero()))
     }
 }
 
@@ -0,0 +1,1024 @@
+//! Robust geometric predicates in any dimension.
+//!
+//! The [orient], [insphere] and [lifted_orient] predicates are the signs of
+//! determinants, which are first evaluated with interval arithmetic. This
+//! is fast, and certifies the sign in all but nearly degenerate cases.
+//! Otherwise, the determinant is evaluated exactly with arbitrary precision
+//! integers, since every finite `f64` is an integer multiple of a power of
+//! two. The answers are therefore always exact, and in particular
+//! consistent with each other.
+
+use num_bigint::BigInt;
+use std::cmp::Ordering;
+
+/// The `orient` function returns the orientation of D + 1 points in
+/// D-dimensional space, i.e. the sign of the determinant
+///
+/// ```text
+/// | p_0 - p_D     |
+/// | ...           |
+/// | p_{D-1} - p_D |
+/// ```
+///
+/// The points are on the same hyperplane if and only if it is zero.
+/// Swapping two points reverses the orientation.
+///
+/// # Arguments:
+///
+/// * `points`: The D + 1 points, which must have finite coordinates.
+///
+/// # Returns:
+///
+/// [Ordering::Greater] if the points are positively oriented,
+/// [Ordering::Less] if they are negatively oriented, and [Ordering::Equal]
+/// if they are affinely dependent or a coordinate is not finite.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::orient;
+/// use std::cmp::Ordering;
+/// assert_eq!(orient(&[[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]), Ordering::Greater);
+/// assert_eq!(orient(&[[0.0, 1.0], [1.0, 0.0], [0.0, 0.0]]), Ordering::Less);
+/// assert_eq!(orient(&[[0.1, 0.1], [0.2, 0.2], [0.3, 0.3]]), Ordering::Equal);
+/// ```
+pub fn orient<const D: usize>(points: &[[f64; D]]) -> Ordering {
+    assert_eq!(points.len(), D + 1, "Orientation needs D + 1 points!");
+    if !points.iter().flatten().all(|c| c.is_finite()) {
+        return Ordering::Equal;
+    }
+
+    let rows: [[Interval; D]; D] = std::array::from_fn(|i| {
+        std::array::from_fn(|j| Interval::from(points[i][j]) - Interval::from(points[D][j]))
+    });
+    if let Some(sign) = interval_det_sign(rows) {
+        return sign;
+    }
+
+    let integers = to_integers(points.iter().flatten());
+    let rows = |i: usize| {
+        (0..D)
+            .map(|j| &integers[i * D + j] - &integers[D * D + j])
+            .collect()
+    };
+    exact_det_sign((0..D).map(rows).collect())
+}
+
+/// The `insphere` function checks if a point is inside the circumsphere of
+/// a D-simplex, using the sign of the lifted determinant
+///
+/// ```text
+/// | x_0 - p    |x_0 - p|^2 |
+/// | ...        ...         |
+/// | x_D - p    |x_D - p|^2 |
+/// ```
+///
+/// times the [orient]ation of the simplex, so that the answer does not
+/// depend on the order of its vertices.
+///
+/// # Arguments:
+///
+/// * `simplex`: The D + 1 vertices of the simplex, which must have finite
+///   coordinates.
+/// * `point`: The point to check.
+///
+/// # Returns:
+///
+/// [Ordering::Greater] if the point is strictly inside the circumsphere,
+/// [Ordering::Less] if it is strictly outside, and [Ordering::Equal] if it
+/// is on the circumsphere, the simplex is degenerate, or a coordinate is
+/// not finite.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::insphere;
+/// use std::cmp::Ordering;
+/// let simplex = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]];
+/// assert_eq!(insphere(&simplex, &[0.5, 0.5]), Ordering::Greater);
+/// assert_eq!(insphere(&simplex, &[0.0, -1.0]), Ordering::Equal);
+/// assert_eq!(insphere(&simplex, &[2.0, 0.0]), Ordering::Less);
+/// ```
+pub fn insphere<const D: usize>(simplex: &[[f64; D]], point: &[f64; D]) -> Ordering {
+    assert_eq!(simplex.len(), D + 1, "Insphere needs D + 1 points!");
+    if !point.iter().all(|c| c.is_finite()) {
+        return Ordering::Equal;
+    }
+    let orientation = orient(simplex);
+    if orientation == Ordering::Equal {
+        return Ordering::Equal;
+    }
+
+    let lifted = lifted_det_sign(simplex, point);
+
+    if orientation == Ordering::Greater {
+        lifted
+    } else {
+        lifted.reverse()
+    }
+}
+
+/// The `lifted_orient` function returns the orientation of D + 2 points
+/// lifted onto the paraboloid `x -> (x, |x|^2)` in (D + 1)-dimensional
+/// space, i.e. the sign of the determinant
+///
+/// ```text
+/// | p_0 - p_{D+1}    |p_0|^2 - |p_{D+1}|^2 |
+/// | ...              ...                   |
+/// | p_D - p_{D+1}    |p_D|^2 - |p_{D+1}|^2 |
+/// ```
+///
+/// which is the lifted determinant of the [insphere] predicate of the
+/// first D + 1 points and the last one. The lifted points are on the same
+/// hyperplane if and only if it is zero, so the lower convex hull of the
+/// lifted points projects onto their Delaunay triangulation.
+///
+/// # Arguments:
+///
+/// * `points`: The D + 2 points, which must have finite coordinates.
+///
+/// # Returns:
+///
+/// [Ordering::Greater] if the lifted points are positively oriented,
+/// [Ordering::Less] if they are negatively oriented, and [Ordering::Equal]
+/// if they are affinely dependent or a coordinate is not finite.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::lifted_orient;
+/// use std::cmp::Ordering;
+/// let circle = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]];
+/// assert_eq!(lifted_orient(&[circle[0], circle[1], circle[2], [0.5, 0.5]]), Ordering::Greater);
+/// assert_eq!(lifted_orient(&[circle[1], circle[0], circle[2], [0.5, 0.5]]), Ordering::Less);
+/// assert_eq!(lifted_orient(&[circle[0], circle[1], circle[2], [0.0, -1.0]]), Ordering::Equal);
+/// assert_eq!(lifted_orient(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [0.0, 1.0]]), Ordering::Greater);
+/// ```
+pub fn lifted_orient<const D: usize>(points: &[[f64; D]]) -> Ordering {
+    assert_eq!(
+        points.len(),
+        D + 2,
+        "Lifted orientation needs D + 2 points!"
+    );
+    if !points.iter().flatten().all(|c| c.is_finite()) {
+        return Ordering::Equal;
+    }
+
+    lifted_det_sign(&points[..=D], &points[D + 1])
+}
+
+/// The `perturbed_insphere` function is the [insphere] predicate with ties
+/// broken by symbolic perturbation, so that no point is ever on the
+/// circumsphere of a simplex unless it is one of its vertices.
+///
+/// Each point is lifted onto the paraboloid with an infinitesimal offset,
+/// which is larger for points which are larger in lexicographic order of
+/// their coordinates. If the point is on the circumsphere, the sign of the
+/// perturbed determinant is given by the largest point whose offset has a
+/// non-zero coefficient: the point itself is then outside, while replacing
+/// the largest such vertex by the point gives the side as an [orient]ation
+/// (cf. Devillers and Teillaud, *Perturbations for Delaunay and weighted
+/// Delaunay 3D triangulations*). The result only depends on the
+/// coordinates, and is consistent across all the simplices and points of a
+/// triangulation, which makes the Delaunay triangulation unique.
+///
+/// # Arguments:
+///
+/// * `simplex`: The D + 1 vertices of the simplex, which must have finite
+///   coordinates.
+/// * `point`: The point to check.
+///
+/// # Returns:
+///
+/// [Ordering::Greater] if the point is inside the perturbed circumsphere,
+/// [Ordering::Less] if it is outside, and [Ordering::Equal] only if it is
+/// a vertex of the simplex, the simplex is degenerate, or a coordinate is
+/// not finite.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::{insphere, perturbed_insphere};
+/// use std::cmp::Ordering;
+/// let simplex = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]];
+/// assert_eq!(insphere(&simplex, &[0.0, -1.0]), Ordering::Equal);
+/// assert_eq!(perturbed_insphere(&simplex, &[0.0, -1.0]), Ordering::Greater);
+/// assert_eq!(perturbed_insphere(&simplex, &[1.0, 0.0]), Ordering::Equal);
+/// ```
+pub fn perturbed_insphere<const D: usize>(simplex: &[[f64; D]], point: &[f64; D]) -> Ordering {
+    if !point.iter().all(|c| c.is_finite()) {
+        return Ordering::Equal;
+    }
+
+    perturb(
+        simplex,
+        point,
+        insphere(simplex, point),
+        orient,
+        lexicographic,
+    )
+}
+
+/// Breaks a tie of an insphere predicate by symbolic perturbation, as
+/// described in [perturbed_insphere], given the unperturbed `side` of the
+/// point and the `orient` predicate and `lexicographic` order of points.
+pub(crate) fn perturb<P: Clone + PartialEq>(
+    simplex: &[P],
+    point: &P,
+    side: Ordering,
+    orient: impl Fn(&[P]) -> Ordering,
+    lexicographic: impl Fn(&P, &P) -> Ordering,
+) -> Ordering {
+    if side != Ordering::Equal || simplex.contains(point) {
+        return side;
+    }
+    let orientation = orient(simplex);
+    if orientation == Ordering::Equal {
+        return Ordering::Equal;
+    }
+
+    // The vertices of the simplex, then the point, from the largest
+    let n = simplex.len();
+    let mut order: Vec<usize> = (0..=n).collect();
+    let coords = |i: usize| if i == n { point } else { &simplex[i] };
+    order.sort_by(|a, b| lexicographic(coords(*b), coords(*a)));
+
+    for i in order {
+        if i == n {
+            return Ordering::Less;
+        }
+        let mut replaced = simplex.to_vec();
+        replaced[i] = point.clone();
+        match orient(&replaced) {
+            Ordering::Equal => continue,
+            o if o == orientation => return Ordering::Greater,
+            _ => return Ordering::Less,
+        }
+    }
+
+    Ordering::Less
+}
+
+/// The `perturbed_lifted_orient` function is the [lifted_orient] predicate
+/// with ties broken by the symbolic perturbation of [perturbed_insphere],
+/// so that it agrees with the [orient]ation of a simplex times its
+/// [perturbed_insphere] predicate, and lifted points are only on the same
+/// hyperplane if they project onto a hyperplane.
+///
+/// The lifted determinant is linear in the offset of each point, whose
+/// coefficient is plus or minus the [orient]ation of the other D + 1
+/// points, so its sign is given by the largest point in lexicographic
+/// order whose coefficient is not zero.
+///
+/// # Arguments:
+///
+/// * `points`: The D + 2 points, which must have finite coordinates.
+///
+/// # Returns:
+///
+/// [Ordering::Greater] if the perturbed lifted points are positively
+/// oriented, [Ordering::Less] if they are negatively oriented, and
+/// [Ordering::Equal] only if two points coincide, the points lie on a
+/// hyperplane, or a coordinate is not finite.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::{lifted_orient, perturbed_lifted_orient};
+/// use std::cmp::Ordering;
+/// let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+/// assert_eq!(lifted_orient(&square), Ordering::Equal);
+/// assert_eq!(perturbed_lifted_orient(&square), Ordering::Greater);
+/// assert_eq!(perturbed_lifted_orient(&[[0.0], [1.0], [2.0]]), Ordering::Greater);
+/// assert_eq!(perturbed_lifted_orient(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]), Ordering::Equal);
+/// ```
+pub fn perturbed_lifted_orient<const D: usize>(points: &[[f64; D]]) -> Ordering {
+    perturb_lifted(points, lifted_orient(points), orient, lexicographic)
+}
+
+/// Breaks a tie of a lifted orientation predicate by symbolic
+/// perturbation, as described in [perturbed_lifted_orient], given the
+/// unperturbed `side` of the points and the `orient` predicate and
+/// `lexicographic` order of points.
+pub(crate) fn perturb_lifted<P: Clone + PartialEq>(
+    points: &[P],
+    side: Ordering,
+    orient: impl Fn(&[P]) -> Ordering,
+    lexicographic: impl Fn(&P, &P) -> Ordering,
+) -> Ordering {
+    if side != Ordering::Equal || (1..points.len()).any(|i| points[..i].contains(&points[i])) {
+        return side;
+    }
+
+    // The offset of the i-th of the D + 2 points has the coefficient
+    // (-1)^(i + D) times the orientation of the other points
+    let n = points.len();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|a, b| lexicographic(&points[*b], &points[*a]));
+
+    for i in order {
+        let others: Vec<P> = points
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .map(|(_, p)| p.clone())
+            .collect();
+        match orient(&others) {
+            Ordering::Equal => continue,
+            o if (i + n) % 2 == 0 => return o,
+            o => return o.reverse(),
+        }
+    }
+
+    Ordering::Equal
+}
+
+/// Compares points in lexicographic order of their coordinates.
+fn lexicographic<const D: usize>(a: &[f64; D], b: &[f64; D]) -> Ordering {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| x.total_cmp(y))
+        .find(|o| *o != Ordering::Equal)
+        .unwrap_or(Ordering::Equal)
+}
+
+/// The `affinely_independent` function checks if points in D-dimensional
+/// space are affinely independent, i.e. if the vectors from the first point
+/// to the others are linearly independent. The rank of these vectors is
+/// computed exactly.
+///
+/// # Arguments:
+///
+/// * `points`: The points to check, which must have finite coordinates.
+///
+/// # Returns:
+///
+/// `true` if the points are affinely independent, and `false` otherwise or
+/// if a coordinate is not finite. In particular, more than D + 1 points are
+/// never affinely independent.
+///
+/// # Example:
+///
+/// ```
+/// use dd_delaunay::delaunay_core::predicates::affinely_independent;
+/// assert!(affinely_independent(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]]));
+/// assert!(!affinely_independent(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 4.0, 6.0]]));
+/// assert!(affinely_independent(&[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 4.0, 7.0]]));
+/// ```
+pub fn affinely_independent<const D: usize>(points: &[[f64; D]]) -> bool {
+    if points.len() > D + 1 || !points.iter().flatten().all(|c| c.is_finite()) {
+        return false;
+    }
+    if points.len() == D + 1 {
+        return orient(points) != Ordering::Equal;
+    }
+
+    let integers = to_integers(points.iter().flatten());
+    let mut matrix: Vec<Vec<BigInt>> = (1..points.len())
+        .map(|i| {
+            (0..D)
+                .map(|j| &integers[i * D + j] - &integers[j])
+                .collect()
+        })
+        .collect();
+
+    // Fraction-free row reduction, counting the pivots
+    let zero = BigInt::from(0);
+    let mut rank = 0;
+    for column in 0..D {
+        let Some(pivot) = (rank..matrix.len()).find(|i| matrix[*i][column] != zero) else {
+            continue;
+        };
+        matrix.swap(pivot, rank);
+        for i in rank + 1..matrix.len() {
+            for j in column + 1..D {
+                matrix[i][j] =
+                    &matrix[i][j] * &matrix[rank][column] - &matrix[i][column] * &matrix[rank][j];
+            }
+            matrix[i][column] = zero.clone();
+        }
+        rank += 1;
+    }
+
+    rank == matrix.len()
+}
+
+/// A closed interval of `f64`s known to contain an exact real value.
+///
+/// The result of each operation is rounded to the nearest `f64` and then
+/// widened outwards by one unit in the last place, so it contains the exact
+/// result of the operation on any values contained in the operands.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Interval {
+    lo: f64,
+    hi: f64,
+}
+
+impl From<f64> for Interval {
+    fn from(value: f64) -> Self {
+        Self {
+            lo: value,
+            hi: value,
+        }
+    }
+}
+
+impl std::ops::Add for Interval {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            lo: next_down(self.lo + other.lo),
+            hi: next_up(self.hi + other.hi),
+        }
+    }
+}
+
+impl std::ops::Sub for Interval {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            lo: next_down(self.lo - other.hi),
+            hi: next_up(self.hi - other.lo),
+        }
+    }
+}
+
+impl std::ops::Mul for Interval {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let products = [
+            self.lo * other.lo,
+            self.lo * other.hi,
+            self.hi * other.lo,
+            self.hi * other.hi,
+        ];
+        Self::widened(products)
+    }
+}
+
+impl std::ops::Div for Interval {
+    type Output = Self;
+
+    /// The divisor must not contain zero.
+    fn div(self, other: Self) -> Self {
+        let quotients = [
+            self.lo / other.lo,
+            self.lo / other.hi,
+            self.hi / other.lo,
+            self.hi / other.hi,
+        ];
+        Self::widened(quotients)
+    }
+}
+
+impl Interval {
+    /// The smallest interval containing the rounded values, widened by one
+    /// unit in the last place.
+    fn widened(values: [f64; 4]) -> Self {
+        let lo = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let hi = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        if values.iter().any(|v| v.is_nan()) {
+            return Self {
+                lo: f64::NEG_INFINITY,
+                hi: f64::INFINITY,
+            };
+        }
+        Self {
+            lo: next_down(lo),
+            hi: next_up(hi),
+        }
+    }
+
+    /// The sign of every value in the interval, if they all have the same.
+    fn sign(self) -> Option<Ordering> {
+        if self.lo > 0.0 {
+            Some(Ordering::Greater)
+        } else if self.hi < 0.0 {
+            Some(Ordering::Less)
+        } else if self.lo == 0.0 && self.hi == 0.0 {
+            Some(Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    /// The smallest absolute value in the interval.
+    fn mignitude(self) -> f64 {
+        if self.lo > 0.0 {
+            self.lo
+        } else if self.hi < 0.0 {
+            -self.hi
+        } else {
+            0.0
+        }
+    }
+}
+
+/// The next `f64` towards positive infinity.
+fn next_up(value: f64) -> f64 {
+    if value.is_nan() || value == f64::INFINITY {
+        value
+    } else if value == 0.0 {
+        f64::from_bits(1)
+    } else if value > 0.0 {
+        f64::from_bits(value.to_bits() + 1)
+    } else {
+        f64::from_bits(value.to_bits() - 1)
+    }
+}
+
+/// The next `f64` towards negative infinity.
+fn next_down(value: f64) -> f64 {
+    -next_up(-value)
+}
+
+/// Returns the sign of the lifted determinant of the [insphere] and
+/// [lifted_orient] predicates, which is evaluated exactly if interval
+/// arithmetic cannot certify it.
+fn lifted_det_sign<const D: usize>(simplex: &[[f64; D]], point: &[f64; D]) -> Ordering {
+    interval_lifted_det_sign(simplex, point).unwrap_or_else(|| {
+        let integers = to_integers(simplex.iter().flatten().chain(point.iter()));
+        let rows = |i: usize| {
+            let mut row: Vec<BigInt> = (0..D)
+                .map(|j| &integers[i * D + j] - &integers[(D + 1) * D + j])
+                .collect();
+            let lift = row.iter().map(|c| c * c).sum();
+            row.push(lift);
+            row
+        };
+        exact_det_sign((0..=D).map(rows).collect())
+    })
+}
+
+/// Returns the sign of the lifted determinant of the [insphere] predicate
+/// with interval arithmetic, or `None` if it cannot be certified.
+///
+/// The rows are the coordinates translated so that the point is at the
+/// origin, lifted onto the paraboloid in the last column. The row with the
+/// largest lift is eliminated, as in
+/// [lifted_determinant](super::matrix::lifted_determinant), so that the
+/// sign is that of the D x D Schur complement, which is on the stack.
+fn interval_lifted_det_sign<const D: usize>(
+    simplex: &[[f64; D]],
+    point: &[f64; D],
+) -> Option<Ordering> {
+    let row = |i: usize| -> [Interval; D] {
+        std::array::from_fn(|j| Interval::from(simplex[i][j]) - Interval::from(point[j]))
+    };
+    let lift = |row: &[Interval; D]| row.iter().fold(Interval::from(0.0), |sum, c| sum + *c * *c);
+
+    let pivot = (0..=D).max_by(|a, b| {
+        lift(&row(*a))
+            .mignitude()
+            .total_cmp(&lift(&row(*b)).mignitude())
+    })?;
+    let pivot_row = row(pivot);
+    let pivot_lift = lift(&pivot_row);
+    if pivot_lift.sign()? != Ordering::Greater {
+        return None;
+    }
+
+    let mut complement = [[Interval::from(0.0); D]; D];
+    for (i, entries) in (0..=D).filter(|i| *i != pivot).zip(complement.iter_mut()) {
+        let row = row(i);
+        let factor = lift(&row) / pivot_lift;
+        for (j, entry) in entries.iter_mut().enumerate() {
+            *entry = row[j] - factor * pivot_row[j];
+        }
+    }
+    let sign = interval_det_sign(complement)?;
+
+    // Moving the pivot row last takes D - pivot swaps
+    if (D - pivot) % 2 == 0 {
+        Some(sign)
+    } else {
+        Some(sign.reverse())
+    }
+}
+
+/// Returns the sign of the determinant of a square matrix of intervals
+/// using Gaussian elimination on the stack, or `None` if a pivot cannot be
+/// certified to be non-zero.
+fn interval_det_sign<const N: usize>(mut matrix: [[Interval; N]; N]) -> Option<Ordering> {
+    let mut sign = Ordering::Greater;
+    for k in 0..N {
+        // The pivot furthest away from zero
+        let pivot = (k..N).max_by(|a, b| {
+            matrix[*a][k]
+                .mignitude()
+                .total_cmp(&matrix[*b][k].mignitude())
+        })?;
+        match matrix[pivot][k].sign()? {
+            Ordering::Equal => {
+                // The remaining column is exactly zero only if every entry is
+                return (k..N)
+                    .all(|i| matrix[i][k].sign() == Some(Ordering::Equal))
+                    .then_some(Ordering::Equal);
+            }
+            Ordering::Less => sign = sign.reverse(),
+            Ordering::Greater => {}
+        }
+        if pivot != k {
+            matrix.swap(pivot, k);
+            sign = sign.reverse();
+        }
+
+        let (pivot_rows, rows) = matrix.split_at_mut(k + 1);
+        let pivot_row = &pivot_rows[k];
+        for row in rows.iter_mut() {
+            let factor = row[k] / pivot_row[k];
+            for (entry, pivot_entry) in row.iter_mut().zip(pivot_row.iter()).skip(k + 1) {
+                *entry = *entry - factor * *pivot_entry;
+            }
+        }
+    }
+
+    Some(sign)
+}
+
+/// Returns the sign of the determinant of a square matrix of integers
+/// using fraction-free Bareiss elimination.
+fn exact_det_sign(mut matrix: Vec<Vec<BigInt>>) -> Ordering {
+    let n = matrix.len();
+    let zero = BigInt::from(0);
+    let mut negate = false;
+    let mut previous = BigInt::from(1);
+    for k in 0..n {
+        let Some(pivot) = (k..n).find(|i| matrix[*i][k] != zero) else {
+            return Ordering::Equal;
+        };
+        if pivot != k {
+            matrix.swap(pivot, k);
+            negate = !negate;
+        }
+
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let value = &matrix[i][j] * &matrix[k][k] - &matrix[i][k] * &matrix[k][j];
+                matrix[i][j] = value / &previous;
+            }
+        }
+        previous = matrix[k][k].clone();
+    }
+
+    let sign = previous.cmp(&zero);
+    if negate {
+        sign.reverse()
+    } else {
+        sign
+    }
+}
+
+/// Converts finite `f64`s to integers, by scaling them all by the same
+/// power of two so that the smallest exponent becomes zero.
+fn to_integers<'a>(values: impl Iterator<Item = &'a f64> + Clone) -> Vec<BigInt> {
+    let decoded: Vec<(i64, i32)> = values.map(|v| decode(*v)).collect();
+    let min_exponent = decoded
+        .iter()
+        .filter(|(mantissa, _)| *mantissa != 0)
+        .map(|(_, exponent)| *exponent)
+        .min()
+        .unwrap_or(0);
+
+    decoded
+        .into_iter()
+        .map(|(mantissa, exponent)| BigInt::from(mantissa) << (exponent - min_exponent) as usize)
+        .collect()
+}
+
+/// Decodes a finite `f64` into an integer mantissa and a binary exponent,
+/// such that the value is `mantissa * 2^exponent`.
+fn decode(value: f64) -> (i64, i32) {
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7ff) as i32;
+    let fraction = (bits & 0xf_ffff_ffff_ffff) as i64;
+    let sign = if bits >> 63 == 0 { 1 } else { -1 };
+
+    // Subnormal numbers have no implicit leading bit
+    if exponent == 0 {
+        (sign * fraction, -1074)
+    } else {
+        (sign * (fraction | 0x10_0000_0000_0000), exponent - 1075)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::delaunay_core::test_utilities::uniform;
+
+    #[test]
+    fn predicates_orient() {
+        assert_eq!(
+            orient(&[
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [0.0, 0.0, 1.0],
+                [0.0, 0.0, 0.0]
+            ]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            orient(&[
+                [0.0, 1.0, 0.0],
+                [1.0, 0.0, 0.0],
+                [0.0, 0.0, 1.0],
+                [0.0, 0.0, 0.0]
+            ]),
+            Ordering::Less
+        );
+        assert_eq!(
+            orient(&[
+                [1.0, 0.0, 0.0],
+                [0.0, 1.0, 0.0],
+                [1.0, 1.0, 0.0],
+                [0.0, 0.0, 0.0]
+            ]),
+            Ordering::Equal
+        );
+        assert_eq!(orient(&[[3.0], [1.0]]), Ordering::Greater);
+        assert_eq!(
+            orient(&[[f64::NAN, 0.0], [0.0, 1.0], [0.0, 0.0]]),
+            Ordering::Equal
+        );
+
+        // Human readable output for cargo test -- --nocapture
+        println!("{:?}", orient(&[[1.0, 0.0], [0.0, 1.0], [0.0, 0.0]]));
+    }
+
+    #[test]
+    fn predicates_orient_nearly_collinear() {
+        // The determinant is 12 * (y - x) * 2^-52, which plain floating-point
+        // evaluation gets wrong for many of these points
+        let ulp = f64::EPSILON;
+        for x in 0..32 {
+            for y in 0..32 {
+                let p = [0.5 + x as f64 * ulp, 0.5 + y as f64 * ulp];
+                let expected = y.cmp(&x);
+                assert_eq!(orient(&[p, [12.0, 12.0], [24.0, 24.0]]), expected);
+                assert_eq!(orient(&[[12.0, 12.0], p, [24.0, 24.0]]), expected.reverse());
+            }
+        }
+    }
+
+    #[test]
+    fn predicates_orient_extreme_exponents() {
+        // The determinant underflows in floating-point
+        assert_eq!(
+            orient(&[[1.0e-200, 0.0], [0.0, 1.0e-200], [0.0, 0.0]]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            orient(&[[1.0e200, 1.0e-200], [1.0e200, 0.0], [0.0, 0.0]]),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn predicates_insphere() {
+        let simplex = [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0],
+        ];
+        assert_eq!(insphere(&simplex, &[0.5, 0.5, 0.5]), Ordering::Greater);
+        assert_eq!(insphere(&simplex, &[2.0, 2.0, 2.0]), Ordering::Less);
+
+        // Every corner of the unit cube is on the circumsphere
+        for corner in [
+            [1.0, 1.0, 0.0],
+            [1.0, 0.0, 1.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0],
+        ] {
+            assert_eq!(insphere(&simplex, &corner), Ordering::Equal);
+        }
+
+        // The answer does not depend on the order of the vertices
+        let swapped = [simplex[1], simplex[0], simplex[2], simplex[3]];
+        assert_eq!(insphere(&swapped, &[0.5, 0.5, 0.5]), Ordering::Greater);
+        assert_eq!(insphere(&swapped, &[2.0, 2.0, 2.0]), Ordering::Less);
+
+        // Degenerate simplices have no circumsphere
+        let flat = [[1.0, 0.0], [2.0, 0.0], [3.0, 0.0]];
+        assert_eq!(insphere(&flat, &[2.0, 1.0]), Ordering::Equal);
+    }
+
+    #[test]
+    fn predicates_insphere_nearly_cospherical() {
+        // Points on the unit circle perturbed by a few units in the last
+        // place; the perturbation along the radius decides the answer
+        let simplex = [[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]];
+        let ulp = f64::EPSILON / 2.0;
+        for k in 1..8 {
+            let inside = [0.0, -1.0 + k as f64 * ulp];
+            let outside = [0.0, -1.0 - k as f64 * 2.0 * ulp];
+            assert_eq!(insphere(&simplex, &inside), Ordering::Greater);
+            assert_eq!(insphere(&simplex, &outside), Ordering::Less);
+        }
+    }
+
+    #[test]
+    fn predicates_exact_agrees_with_intervals() {
+        let mut next = uniform(3);
+
+        for _ in 0..100 {
+            let values: Vec<f64> = (0..16).map(|_| next()).collect();
+            let intervals: [[Interval; 4]; 4] =
+                std::array::from_fn(|i| std::array::from_fn(|j| Interval::from(values[i * 4 + j])));
+            let integers = to_integers(values.iter());
+            let exact = integers.chunks(4).map(|row| row.to_vec()).collect();
+            if let Some(sign) = interval_det_sign(intervals) {
+                assert_eq!(sign, exact_det_sign(exact));
+            }
+        }
+    }
+
+    #[test]
+    fn predicates_lifted_intervals_agree_with_exact() {
+        let mut next = uniform(5);
+
+        for _ in 0..100 {
+            let simplex: [[f64; 3]; 4] = std::array::from_fn(|_| [next(), next(), next()]);
+            let point = [next(), next(), next()];
+            let integers = to_integers(simplex.iter().flatten().chain(point.iter()));
+            let exact = (0..4)
+                .map(|i| {
+                    let mut row: Vec<BigInt> = (0..3)
+                        .map(|j| &integers[i * 3 + j] - &integers[12 + j])
+                        .collect();
+                    row.push(row.iter().map(|c| c * c).sum());
+                    row
+                })
+                .collect();
+            if let Some(sign) = interval_lifted_det_sign(&simplex, &point) {
+                assert_eq!(sign, exact_det_sign(exact));
+            }
+        }
+    }
+
+    #[test]
+    fn predicates_perturbed_insphere() {
+        let simplex = [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0],
+        ];
+        assert_eq!(
+            perturbed_insphere(&simplex, &[0.5, 0.5, 0.5]),
+            Ordering::Greater
+        );
+        assert_eq!(
+            perturbed_insphere(&simplex, &[2.0, 2.0, 2.0]),
+            Ordering::Less
+        );
+        for vertex in simplex.iter() {
+            assert_eq!(perturbed_insphere(&simplex, vertex), Ordering::Equal);
+        }
+
+        // Ties are broken the same way whatever the order of the vertices
+        let swapped = [simplex[2], simplex[0], simplex[3], simplex[1]];
+        for corner in [
+            [1.0, 1.0, 0.0],
+            [1.0, 0.0, 1.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0],
+        ] {
+            let side = perturbed_insphere(&simplex, &corner);
+            assert_ne!(side, Ordering::Equal);
+            assert_eq!(perturbed_insphere(&swapped, &corner), side);
+        }
+    }
+
+    #[test]
+    fn predicates_perturbed_insphere_is_consistent() {
+        // Of the two triangulations of four cocircular points, exactly one is
+        // Delaunay for the perturbed predicate
+        let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let diagonal = |a: usize, b: usize, c: usize, d: usize| {
+            perturbed_insphere(&[square[a], square[b], square[c]], &square[d]) == Ordering::Less
+                && perturbed_insphere(&[square[a], square[c], square[d]], &square[b])
+                    == Ordering::Less
+        };
+        assert_ne!(diagonal(0, 1, 2, 3), diagonal(1, 2, 3, 0));
+    }
+
+    #[test]
+    fn predicates_lifted_orient() {
+        // The lifted orientation is the insphere predicate times the
+        // orientation of the simplex
+        let simplex = [
+            [1.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [0.0, 0.0, 0.0],
+        ];
+        let swapped = [simplex[1], simplex[0], simplex[2], simplex[3]];
+        for point in [[0.5, 0.5, 0.5], [2.0, 2.0, 2.0], [1.0, 1.0, 1.0]] {
+            for simplex in [simplex, swapped] {
+                let lifted = [simplex[0], simplex[1], simplex[2], simplex[3], point];
+                let expected = match orient(&simplex) {
+                    Ordering::Less => insphere(&simplex, &point).reverse(),
+                    _ => insphere(&simplex, &point),
+                };
+                assert_eq!(lifted_orient(&lifted), expected);
+            }
+        }
+
+        // Points on a hyperplane lift onto a vertical hyperplane
+        assert_eq!(
+            lifted_orient(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [5.0, 5.0]]),
+            Ordering::Equal
+        );
+        assert_eq!(
+            lifted_orient(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0], [f64::NAN, 0.0]]),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn predicates_perturbed_lifted_orient() {
+        // The perturbation agrees with that of the insphere predicate on
+        // the cospherical points of a grid
+        let grid: Vec<[f64; 2]> = (0..9).map(|i| [(i % 3) as f64, (i / 3) as f64]).collect();
+        for a in 0..9 {
+            for b in 0..9 {
+                for c in 0..9 {
+                    let simplex = [grid[a], grid[b], grid[c]];
+                    let orientation = orient(&simplex);
+                    if orientation == Ordering::Equal {
+                        continue;
+                    }
+                    for point in grid.iter() {
+                        let lifted = [simplex[0], simplex[1], simplex[2], *point];
+                        let side = perturbed_insphere(&simplex, point);
+                        let expected = match orientation {
+                            Ordering::Less => side.reverse(),
+                            _ => side,
+                        };
+                        assert_eq!(perturbed_lifted_orient(&lifted), expected);
+
+                        // Swapping two points reverses the orientation
+                        let swapped = [*point, simplex[1], simplex[2], simplex[0]];
+                        assert_eq!(perturbed_lifted_orient(&swapped), expected.reverse());
+                    }
+                }
+            }
+        }
+
+        // Only points projecting onto a hyperplane remain degenerate
+        let collinear = [[0.0, 0.0], [0.0, 1.0], [0.0, 2.0], [0.0, 3.0]];
+        assert_eq!(perturbed_lifted_orient(&collinear), Ordering::Equal);
+        let lifted = [[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [0.0, 1.0]];
+        assert_ne!(perturbed_lifted_orient(&lifted), Ordering::Equal);
+    }
+
+    #[test]
+    fn predicates_affinely_independent() {
+        assert!(affinely_independent::<3>(&[]));
+        assert!(affinely_independent(&[[1.0, 2.0, 3.0]]));
+        assert!(!affinely_independent(&[[1.0, 2.0, 3.0], [1.0, 2.0, 3.0]]));
+
+        // Nearly collinear points
+        let ulp = f64::EPSILON;
+        let p = [0.5 + ulp, 0.5, 0.5];
+        assert!(affinely_independent(&[
+            p,
+            [12.0, 12.0, 12.0],
+            [24.0, 24.0, 24.0]
+        ]));
+        assert!(!affinely_independent(&[
+            [0.5, 0.5, 0.5],
+            [12.0, 12.0, 12.0],
+            [24.0, 24.0, 24.0]
+        ]));
+
+        // Coplanar points, and too many points
+        let square = [
+            [0.0, 0.0, 1.0],
+            [1.0, 0.0, 1.0],
+            [0.0, 1.0, 1.0],
+            [1.0, 1.0, 1.0],
+        ];
+        assert!(affinely_independent(&square[..3]));
+        assert!(!affinely_independent(&square));
+        let plane = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0], [1.0, 1.0]];
+        assert!(!affinely_independent(&plane));
+    }
+
+    #[test]
+    fn predicates_decode() {
+        for value in [0.0, 1.0, -1.5, 0.1, 1.0e300, -1.0e-310, f64::MIN_POSITIVE] {
+            let (mantissa, exponent) = decode(value);
+            let half = exponent / 2;
+            let decoded = mantissa as f64 * 2.0_f64.powi(half) * 2.0_f64.powi(exponent - half);
+            assert_eq!(decoded, value);
+        }
+    }
+}
@@ -0,0 +1,340 @@
+//! Spatial sorting of points before bulk construction.
+//!
+//! Inserting points in an order which preserves locality keeps the walks
+//! locating each point short, as consecutive points are close to each other.
+//! Points are ordered along a d-dimensional
+//! [Hilbert curve](https://en.wikipedia.org/wiki/Hilbert_curve), or in a
+//! biased randomized insertion order (BRIO) of rounds of increasing size,
+//! each ordered along the curve, as in
+//! [CGAL](https://doc.cgal.org/latest/Spatial_sorting/index.html).
+
+use super::{kernel::CoordinateScalar, point::Point};
+use crate::Coord;
+
+/// The seed of the pseudo-random insertion orders, fixed so that
+/// construction is reproducible.
+const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// The order in which the points of a bulk construction are inserted.
+pub enum InsertionOrder {
+    /// The order the vertices are stored in.
+    Storage,
+    /// The order along a Hilbert curve through the bounding box.
+    Hilbert,
+    /// A biased randomized insertion order, whose rounds are each ordered
+    /// along a Hilbert curve.
+    #[default]
+    Brio,
+    /// A pseudo-random order, as assumed by randomized incremental
+    /// construction.
+    Random,
+}
+
+impl InsertionOrder {
+    /// The function `order` returns the indices of the points in the order
+    /// they should be inserted.
+    ///
+    /// # Arguments:
+    ///
+    /// * `points`: The points to order.
+    ///
+    /// # Returns:
+    ///
+    /// A permutation of the indices of `points`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// use dd_delaunay::delaunay_core::spatial_sort::InsertionOrder;
+    /// let points = vec![
+    ///     Point::new([0.0, 0.0]),
+    ///     Point::new([1.0, 1.0]),
+    ///     Point::new([0.1, 0.0]),
+    /// ];
+    /// assert_eq!(InsertionOrder::Storage.order(&points), vec![0, 1, 2]);
+    /// assert_eq!(InsertionOrder::Hilbert.order(&points), vec![0, 2, 1]);
+    /// ```
+    pub fn order<T, const D: usize>(&self, points: &[Point<T, D>]) -> Vec<usize>
+    where
+        T: CoordinateScalar,
+        [T; D]: Coord,
+    {
+        match self {
+            InsertionOrder::Storage => (0..points.len()).collect(),
+            InsertionOrder::Hilbert => hilbert_order(points),
+            InsertionOrder::Brio => brio_order(points, SEED),
+            InsertionOrder::Random => random_order(points.len(), SEED),
+        }
+    }
+}
+
+/// The function `hilbert_order` sorts points along a Hilbert curve through
+/// their bounding box.
+///
+/// # Arguments:
+///
+/// * `points`: The points to sort.
+///
+/// # Returns:
+///
+/// The indices of `points` in the order they are visited by the curve.
+/// Points at the same position on the curve keep their relative order.
+pub fn hilbert_order<T, const D: usize>(points: &[Point<T, D>]) -> Vec<usize>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    let keys = hilbert_keys(points);
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by_key(|i| keys[*i]);
+
+    order
+}
+
+/// The function `brio_order` sorts points in a biased randomized insertion
+/// order. Each point is in round `r` with probability `2^-(r + 1)`, the
+/// rounds are inserted from the smallest to the largest, and the points of
+/// each round are sorted along a Hilbert curve.
+///
+/// # Arguments:
+///
+/// * `points`: The points to sort.
+/// * `seed`: The seed of the pseudo-random rounds.
+///
+/// # Returns:
+///
+/// The indices of `points` in insertion order, which only depends on the
+/// points and the seed.
+pub fn brio_order<T, const D: usize>(points: &[Point<T, D>], seed: u64) -> Vec<usize>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    let keys = hilbert_keys(points);
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by_key(|i| {
+        let round = split_mix(seed ^ *i as u64).trailing_zeros();
+        (std::cmp::Reverse(round), keys[*i])
+    });
+
+    order
+}
+
+/// The function `random_order` returns a pseudo-random permutation of the
+/// indices of `n` points.
+///
+/// # Arguments:
+///
+/// * `n`: The number of points.
+/// * `seed`: The seed of the permutation.
+///
+/// # Returns:
+///
+/// A permutation of `0..n`, which only depends on `n` and the seed.
+pub fn random_order(n: usize, seed: u64) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by_key(|i| split_mix(seed ^ *i as u64));
+
+    order
+}
+
+/// Returns the position of each point on a Hilbert curve through the
+/// bounding box of the points, with each coordinate quantized to as many
+/// bits as fit in a [u128] key.
+fn hilbert_keys<T, const D: usize>(points: &[Point<T, D>]) -> Vec<u128>
+where
+    T: CoordinateScalar,
+    [T; D]: Coord,
+{
+    if D == 0 {
+        return vec![0; points.len()];
+    }
+    let bits = (128 / D).clamp(1, 32) as u32;
+    let scale = ((1u64 << bits) - 1) as f64;
+
+    let mut min = [f64::INFINITY; D];
+    let mut max = [f64::NEG_INFINITY; D];
+    for point in points.iter() {
+        for (i, coord) in point.coords.iter().enumerate() {
+            let coord = coord.to_f64();
+            if coord.is_finite() {
+                min[i] = min[i].min(coord);
+                max[i] = max[i].max(coord);
+            }
+        }
+    }
+
+    points
+        .iter()
+        .map(|point| {
+            let mut axes = [0u32; D];
+            for (i, coord) in point.coords.iter().enumerate() {
+                let extent = max[i] - min[i];
+                let t = (coord.to_f64() - min[i]) / extent;
+                if t.is_finite() {
+                    axes[i] = (t.clamp(0.0, 1.0) * scale) as u32;
+                }
+            }
+            hilbert_index(&mut axes, bits)
+        })
+        .collect()
+}
+
+/// Returns the Hilbert index of quantized coordinates with `bits` bits
+/// each, using Skilling's transform to the transposed index
+/// (cf. "Programming the Hilbert curve", AIP Conf. Proc. 707, 2004).
+fn hilbert_index(axes: &mut [u32], bits: u32) -> u128 {
+    let n = axes.len();
+    let m = 1u32 << (bits - 1);
+
+    // Inverse undo excess work
+    let mut q = m;
+    while q > 1 {
+        let p = q - 1;
+        for i in 0..n {
+            if axes[i] & q != 0 {
+                axes[0] ^= p;
+            } else {
+                let t = (axes[0] ^ axes[i]) & p;
+                axes[0] ^= t;
+                axes[i] ^= t;
+            }
+        }
+        q >>= 1;
+    }
+
+    // Gray encode
+    for i in 1..n {
+        axes[i] ^= axes[i - 1];
+    }
+    let mut t = 0;
+    let mut q = m;
+    while q > 1 {
+        if axes[n - 1] & q != 0 {
+            t ^= q - 1;
+        }
+        q >>= 1;
+    }
+    for axis in axes.iter_mut() {
+        *axis ^= t;
+    }
+
+    // Interleave the bits of the transposed index, most significant first
+    let mut index = 0u128;
+    for b in (0..bits).rev() {
+        for axis in axes.iter() {
+            index = (index << 1) | u128::from((axis >> b) & 1);
+        }
+    }
+
+    index
+}
+
+/// The SplitMix64 finalizer, which maps consecutive integers to
+/// well-distributed pseudo-random bits.
+fn split_mix(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    /// The points of a `n` by `n` grid, in row-major order.
+    fn grid(n: usize) -> Vec<Point<f64, 2>> {
+        (0..n * n)
+            .map(|i| Point::new([(i % n) as f64, (i / n) as f64]))
+            .collect()
+    }
+
+    fn is_permutation(order: &[usize], n: usize) -> bool {
+        let mut sorted = order.to_vec();
+        sorted.sort();
+        sorted == (0..n).collect::<Vec<usize>>()
+    }
+
+    #[test]
+    fn spatial_sort_hilbert_is_continuous() {
+        // Consecutive cells of a 2^k grid are adjacent along the curve
+        let points = grid(16);
+        let order = hilbert_order(&points);
+
+        assert!(is_permutation(&order, points.len()));
+        for pair in order.windows(2) {
+            let (a, b) = (points[pair[0]].coords, points[pair[1]].coords);
+            assert_eq!((a[0] - b[0]).abs() + (a[1] - b[1]).abs(), 1.0);
+        }
+    }
+
+    #[test]
+    fn spatial_sort_hilbert_3d() {
+        let points: Vec<Point<f64, 3>> = (0..64)
+            .map(|i| Point::new([(i % 4) as f64, (i / 4 % 4) as f64, (i / 16) as f64]))
+            .collect();
+        let order = hilbert_order(&points);
+
+        assert!(is_permutation(&order, points.len()));
+        for pair in order.windows(2) {
+            let (a, b) = (points[pair[0]].coords, points[pair[1]].coords);
+            let distance: f64 = a.iter().zip(b.iter()).map(|(x, y)| (x - y).abs()).sum();
+            assert_eq!(distance, 1.0);
+        }
+    }
+
+    #[test]
+    fn spatial_sort_brio() {
+        let points = grid(32);
+        let order = brio_order(&points, SEED);
+
+        assert!(is_permutation(&order, points.len()));
+        assert_eq!(order, InsertionOrder::Brio.order(&points));
+        assert_ne!(order, brio_order(&points, 1));
+
+        // The last round holds about half of the points, along the curve
+        let keys = hilbert_keys(&points);
+        let last = order
+            .iter()
+            .rev()
+            .take_while(|i| split_mix(SEED ^ **i as u64).trailing_zeros() == 0)
+            .count();
+        assert!(last > points.len() / 3 && last < 2 * points.len() / 3);
+        assert!(order[order.len() - last..]
+            .windows(2)
+            .all(|pair| keys[pair[0]] <= keys[pair[1]]));
+    }
+
+    #[test]
+    fn spatial_sort_random() {
+        let points = grid(8);
+        let order = InsertionOrder::Random.order(&points);
+
+        assert!(is_permutation(&order, points.len()));
+        assert_eq!(order, random_order(points.len(), SEED));
+        assert_ne!(order, random_order(points.len(), 1));
+        assert_ne!(order, (0..points.len()).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn spatial_sort_degenerate() {
+        let empty: Vec<Point<f64, 3>> = Vec::new();
+        assert!(InsertionOrder::Brio.order(&empty).is_empty());
+
+        // Points on a line, and repeated points
+        let points = vec![
+            Point::new([0.0, 1.0]),
+            Point::new([0.0, 1.0]),
+            Point::new([0.0, 3.0]),
+            Point::new([0.0, 2.0]),
+        ];
+        assert!(is_permutation(&hilbert_order(&points), points.len()));
+
+        let points = vec![Point::new([1i64, 2]), Point::new([i64::MAX, i64::MIN])];
+        assert!(is_permutation(&hilbert_order(&points), points.len()));
+    }
+}
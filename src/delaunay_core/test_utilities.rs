@@ -0,0 +1,30 @@
+//! Fixtures shared by the tests of the modules.
+
+use super::point::Point;
+use crate::Coord;
+
+/// Returns a generator of pseudo-random numbers in `[-1, 1)` from a linear
+/// congruential generator, so the tests are reproducible without a random
+/// number generator dependency.
+pub(crate) fn uniform(seed: u64) -> impl FnMut() -> f64 {
+    let mut state = seed;
+    move || {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((state >> 11) as f64 / (1u64 << 53) as f64) * 2.0 - 1.0
+    }
+}
+
+/// Returns `n` pseudo-random points in the cube `[-scale, scale)^D`, as per
+/// [uniform].
+pub(crate) fn random_points<const D: usize>(n: usize, seed: u64, scale: f64) -> Vec<Point<f64, D>>
+where
+    [f64; D]: Coord,
+{
+    let mut next = uniform(seed);
+
+    (0..n)
+        .map(|_| Point::new(std::array::from_fn(|_| next() * scale)))
+        .collect()
+}
@@ -64,21 +64,26 @@ pub fn find_extreme_coordinates<T, U, const D: usize>(
     ordering: Ordering,
 ) -> [T; D]
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
-    let mut min_coords = [Default::default(); D];
+    // Start from the first vertex so the result is always one of the input
+    // coordinates rather than the default value
+    let mut extreme_coords = match vertices.values().next() {
+        Some(vertex) => vertex.point.coords.clone(),
+        None => return std::array::from_fn(|_| Default::default()),
+    };
 
     for vertex in vertices.values() {
         for (i, coord) in vertex.point.coords.iter().enumerate() {
-            if coord.partial_cmp(&min_coords[i]) == Some(ordering) {
-                min_coords[i] = *coord;
+            if coord.partial_cmp(&extreme_coords[i]) == Some(ordering) {
+                extreme_coords[i] = coord.clone();
             }
         }
     }
 
-    min_coords
+    extreme_coords
 }
 
 /// The function `vec_to_array` converts a [Vec] to an array of f64
@@ -154,4 +159,34 @@ mod tests {
         // Human readable output for cargo test -- --nocapture
         println!("max_coords = {:?}", max_coords);
     }
+
+    #[test]
+    fn utilities_find_extreme_coordinates_same_sign() {
+        let points = vec![
+            Point::new([1.0, 2.0, 3.0]),
+            Point::new([4.0, 5.0, 6.0]),
+            Point::new([-7.0, -8.0, -9.0]),
+            Point::new([-10.0, -11.0, -12.0]),
+        ];
+        let vertices: Vec<Vertex<f64, Option<()>, 3>> = Vertex::from_points(points);
+        let hashmap = Vertex::into_hashmap(vertices);
+        let positive: HashMap<Uuid, Vertex<f64, Option<()>, 3>> = hashmap
+            .clone()
+            .into_iter()
+            .filter(|(_, v)| v.point.coords[0] > 0.0)
+            .collect();
+        let negative: HashMap<Uuid, Vertex<f64, Option<()>, 3>> = hashmap
+            .into_iter()
+            .filter(|(_, v)| v.point.coords[0] < 0.0)
+            .collect();
+
+        assert_eq!(
+            find_extreme_coordinates(positive, Ordering::Less),
+            [1.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            find_extreme_coordinates(negative, Ordering::Greater),
+            [-7.0, -8.0, -9.0]
+        );
+    }
 }
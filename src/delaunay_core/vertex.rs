@@ -1,6 +1,6 @@
 //! Data and operations on d-dimensional [vertices](https://en.wikipedia.org/wiki/Vertex_(computer_graphics)).
 
-use super::{point::Point, utilities::make_uuid};
+use super::{point::Point, triangulation_data_structure::CellKey, utilities::make_uuid};
 use crate::Coord;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -19,9 +19,10 @@ use uuid::Uuid;
 ///   the vertex in a D-dimensional space.
 /// * `uuid`: A [Uuid] representing a universally unique identifier for the
 ///   for the [Vertex]. This can be used to uniquely
-///   identify the vertex in a graph or any other data structure.
-/// * `incident_cell`: The `incident_cell` property is an optional [Uuid] that
-///   represents a `Cell` containing the [Vertex]. This is
+///   identify the vertex in a graph or any other data structure, while
+///   within a `Tds` the [Vertex] is addressed by its `VertexKey`.
+/// * `incident_cell`: The `incident_cell` property is an optional [CellKey]
+///   of a `Cell` containing the [Vertex]. This is
 ///   calculated by the `delaunay_core::triangulation_data_structure::Tds`.
 /// * `data`: The `data` property is an optional field that can hold any
 ///   type `U`. It is used to store additional data associated with the vertex.
@@ -32,7 +33,7 @@ use uuid::Uuid;
 /// implements Eq, Hash, Ord, PartialEq, and PartialOrd.
 pub struct Vertex<T, U, const DIMS: usize>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; DIMS]: Coord,
 {
@@ -41,9 +42,9 @@ where
     /// A universally unique identifier for the vertex.
     #[builder(setter(skip), default = "make_uuid()")]
     pub uuid: Uuid,
-    /// The [Uuid] of the `Cell` that the vertex is incident to.
+    /// The [CellKey] of the `Cell` that the vertex is incident to.
     #[builder(setter(skip), default = "None")]
-    pub incident_cell: Option<Uuid>,
+    pub incident_cell: Option<CellKey>,
     /// Optional data associated with the vertex.
     #[builder(setter(into, strip_option), default)]
     pub data: Option<U>,
@@ -51,7 +52,7 @@ where
 
 impl<T, U, const D: usize> Vertex<T, U, D>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
@@ -113,20 +114,39 @@ where
 
     /// The function is_valid checks if a [Vertex] is valid.
     ///
+    /// Whether the [Uuid] is unique and the `incident_cell` contains the
+    /// [Vertex] depends on the rest of the triangulation, so those are checked
+    /// by `Tds::validate` instead.
+    ///
     /// # Returns:
     ///
-    /// True if the [Vertex] is valid; the [Point] is correct, the [Uuid] is
-    /// valid and unique, and the `incident_cell` contains the [Uuid] of a
-    /// `Cell` that contains the [Vertex].
-    pub fn is_valid(self) -> bool {
-        todo!("Implement is_valid for Vertex")
+    /// True if the [Vertex] is valid; the coordinates of the [Point] are
+    /// comparable (i.e. not NaN), and the [Uuid] is not nil.
+    ///
+    /// # Example:
+    /// ```
+    /// use dd_delaunay::delaunay_core::vertex::{Vertex, VertexBuilder};
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// let vertex: Vertex<f64, Option<()>, 3> = VertexBuilder::default().point(Point::new([1.0, 2.0, 3.0])).build().unwrap();
+    /// assert!(vertex.is_valid());
+    /// let nan_vertex: Vertex<f64, Option<()>, 3> = VertexBuilder::default().point(Point::new([1.0, f64::NAN, 3.0])).build().unwrap();
+    /// assert!(!nan_vertex.is_valid());
+    /// ```
+    pub fn is_valid(&self) -> bool {
+        let point_is_valid = self
+            .point
+            .coords
+            .iter()
+            .all(|coord| coord.partial_cmp(coord).is_some());
+
+        point_is_valid && !self.uuid.is_nil()
     }
 }
 
 /// Equality of vertices is based on equality of elements in vector of coords.
 impl<T, U, const D: usize> PartialEq for Vertex<T, U, D>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
@@ -142,7 +162,7 @@ where
 /// Order of vertices is based on lexicographic order of elements in vector of coords.
 impl<T, U, const D: usize> PartialOrd for Vertex<T, U, D>
 where
-    T: Clone + Copy + Default + PartialEq + PartialOrd,
+    T: Clone + Default + PartialEq + PartialOrd,
     U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
     [T; D]: Coord,
 {
@@ -255,8 +275,8 @@ mod tests {
 
         assert_eq!(values.len(), 3);
 
-        values.sort_by(|a, b| a.uuid.cmp(&b.uuid));
-        vertices.sort_by(|a, b| a.uuid.cmp(&b.uuid));
+        values.sort_by_key(|v| v.uuid);
+        vertices.sort_by_key(|v| v.uuid);
 
         assert_eq!(values, vertices);
 
@@ -274,6 +294,23 @@ mod tests {
         assert_eq!(vertex.dim(), 3);
     }
 
+    #[test]
+    fn vertex_is_valid() {
+        let vertex: Vertex<f64, Option<()>, 3> = VertexBuilder::default()
+            .point(Point::new([1.0, 2.0, 3.0]))
+            .build()
+            .unwrap();
+        let default_vertex: Vertex<f64, Option<()>, 3> = Default::default();
+        let nan_vertex: Vertex<f64, Option<()>, 3> = VertexBuilder::default()
+            .point(Point::new([f64::NAN, 2.0, 3.0]))
+            .build()
+            .unwrap();
+
+        assert!(vertex.is_valid());
+        assert!(!default_vertex.is_valid());
+        assert!(!nan_vertex.is_valid());
+    }
+
     #[test]
     fn vertex_to_and_from_json() {
         // let vertex: Vertex<f64, Option<()>, 3> = Vertex::new(Point::new([1.0, 2.0, 3.0]));
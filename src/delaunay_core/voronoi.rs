@@ -0,0 +1,1316 @@
+//! The Voronoi diagram of the vertices of a triangulation, as the dual of
+//! their Delaunay triangulation.
+//!
+//! Each k-face of a Delaunay triangulation in D dimensions is dual to a
+//! (D - k)-face of the Voronoi diagram, whose points are equidistant from
+//! the vertices of the k-face and further away from every other vertex:
+//!
+//! * a [Cell](super::cell::Cell) is dual to a [VoronoiVertex], at its
+//!   circumcenter,
+//! * a face shared by several cells is dual to a [VoronoiFace], the convex
+//!   hull of their circumcenters,
+//! * a [Vertex](super::vertex::Vertex) is dual to its [VoronoiCell].
+//!
+//! Faces on the boundary of the convex hull of the vertices are dual to
+//! unbounded faces, which also extend along a [VoronoiRay] out of each hull
+//! facet containing them (cf. [Tds::voronoi]).
+//!
+//! For domain decomposition, the cells are clipped to a bounded convex
+//! [Domain], such as a box, with floating-point approximations of their
+//! volumes, centroids and face areas, up to a tolerance on incidences
+//! (cf. [Voronoi::clipped_cell]).
+
+use super::{
+    convex_hull::{quickhull, ConvexHull, Hyperplane},
+    facet::Facet,
+    kernel::{CoordinateScalar, FilteredKernel, Kernel},
+    matrix::{determinant, solve},
+    point::Point,
+    triangulation_data_structure::{CellKey, Tds, VertexKey},
+};
+use crate::Coord;
+use slotmap::SecondaryMap;
+use std::{collections::HashMap, hash::Hash};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The [VoronoiVertex] struct is a vertex of a [Voronoi] diagram, dual to a
+/// finite [Cell](super::cell::Cell) of the triangulation.
+///
+/// # Properties:
+///
+/// * `cell`: The [CellKey] of the [Cell](super::cell::Cell) it is dual to.
+/// * `point`: The circumcenter of the [Cell](super::cell::Cell), as per
+///   [Cell::circumcenter](super::cell::Cell::circumcenter).
+pub struct VoronoiVertex<F, const D: usize>
+where
+    F: CoordinateScalar,
+    [F; D]: Coord,
+{
+    /// The [CellKey] of the dual [Cell](super::cell::Cell).
+    pub cell: CellKey,
+    /// The circumcenter of the dual [Cell](super::cell::Cell).
+    pub point: Point<F, D>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The [VoronoiRay] struct is an unbounded edge of a [Voronoi] diagram,
+/// dual to a facet on the convex hull of the vertices of the triangulation.
+///
+/// # Properties:
+///
+/// * `facet`: The [Facet] of the finite [Cell](super::cell::Cell) on the
+///   hull it is dual to.
+/// * `origin`: The index in [Voronoi::vertices] of the circumcenter of the
+///   [Cell](super::cell::Cell) the ray starts from.
+/// * `direction`: The unit outward normal of the hull facet, along which
+///   the points remain equidistant from its vertices.
+pub struct VoronoiRay<F, const D: usize> {
+    /// The dual [Facet] on the hull.
+    pub facet: Facet,
+    /// The index of the [VoronoiVertex] the ray starts from.
+    pub origin: usize,
+    /// The unit direction of the ray.
+    pub direction: [F; D],
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// The [VoronoiFace] struct is a face of a [Voronoi] diagram, dual to a
+/// face of the triangulation shared by one or more cells.
+///
+/// # Properties:
+///
+/// * `dual`: The sorted [VertexKey]s of the vertices of the dual face of
+///   the triangulation, from which the points of the [VoronoiFace] are
+///   equidistant.
+/// * `dimension`: The dimension of the [VoronoiFace], which is D + 1 minus
+///   the number of vertices of the dual face.
+/// * `vertices`: The indices in [Voronoi::vertices] of the circumcenters of
+///   the cells sharing the dual face.
+/// * `rays`: The indices in [Voronoi::rays] of the rays out of the hull
+///   facets containing the dual face, along which the [VoronoiFace] is
+///   unbounded.
+pub struct VoronoiFace {
+    /// The sorted vertices of the dual face.
+    pub dual: Vec<VertexKey>,
+    /// The dimension of the face.
+    pub dimension: usize,
+    /// The indices of the vertices of the face.
+    pub vertices: Vec<usize>,
+    /// The indices of the rays of the face.
+    pub rays: Vec<usize>,
+}
+
+impl VoronoiFace {
+    /// The function `is_bounded` checks if the [VoronoiFace] is bounded,
+    /// i.e. if its dual face is not on the convex hull of the vertices.
+    pub fn is_bounded(&self) -> bool {
+        self.rays.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// The [VoronoiCell] struct is the cell of a [Voronoi] diagram around a
+/// vertex of the triangulation, i.e. the points which are closer to it
+/// than to any other vertex, as a polytope with its faces.
+///
+/// # Properties:
+///
+/// * `site`: The [VertexKey] of the vertex it is dual to.
+/// * `vertices`: The indices in [Voronoi::vertices] of its vertices, which
+///   are the circumcenters of the cells incident to the `site`.
+/// * `rays`: The indices in [Voronoi::rays] of its rays, which are those of
+///   the hull facets incident to the `site`.
+/// * `faces`: The indices in [Voronoi::faces] of its faces of every
+///   dimension, from its facets, which are dual to the edges incident to
+///   the `site`, down to its vertices.
+pub struct VoronoiCell {
+    /// The vertex the cell is dual to.
+    pub site: VertexKey,
+    /// The indices of the vertices of the cell.
+    pub vertices: Vec<usize>,
+    /// The indices of the rays of the cell.
+    pub rays: Vec<usize>,
+    /// The indices of the faces of the cell.
+    pub faces: Vec<usize>,
+}
+
+impl VoronoiCell {
+    /// The function `is_bounded` checks if the [VoronoiCell] is bounded,
+    /// i.e. if its `site` is not on the convex hull of the vertices.
+    pub fn is_bounded(&self) -> bool {
+        self.rays.is_empty()
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+/// The [Voronoi] struct is the Voronoi diagram of the vertices of a [Tds],
+/// as the dual of its Delaunay triangulation.
+///
+/// The vertices of a [Tds] without cells, such as one whose vertices span a
+/// lower dimensional affine subspace, have no [VoronoiCell].
+pub struct Voronoi<F, const D: usize>
+where
+    F: CoordinateScalar,
+    [F; D]: Coord,
+{
+    /// The vertices, one per finite [Cell](super::cell::Cell).
+    pub vertices: Vec<VoronoiVertex<F, D>>,
+    /// The rays, one per hull facet.
+    pub rays: Vec<VoronoiRay<F, D>>,
+    /// The faces of dimension 0 to D - 1, one per face of the triangulation
+    /// with 2 to D + 1 vertices.
+    pub faces: Vec<VoronoiFace>,
+    /// The cells, keyed by the [VertexKey] of their `site`.
+    pub cells: SecondaryMap<VertexKey, VoronoiCell>,
+}
+
+impl<F, const D: usize> Voronoi<F, D>
+where
+    F: CoordinateScalar,
+    [F; D]: Coord,
+{
+    /// The function `from_tds` builds the [Voronoi] diagram of the vertices
+    /// of a Delaunay triangulation as its dual.
+    ///
+    /// The vertices are the circumcenters of the finite cells, and the rays
+    /// go out of those of the cells on the convex hull along the outward
+    /// normals of their hull facets, as per [Tds::convex_hull]. Each face
+    /// of the triangulation shared by the cells with vertices in common then
+    /// gives the [VoronoiFace] spanned by their circumcenters and the rays
+    /// of the hull facets containing it.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The Delaunay triangulation.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [Voronoi] diagram, or an error if the
+    /// `neighbors` of the cells are not assigned or a
+    /// [Cell](super::cell::Cell) is degenerate.
+    pub fn from_tds<T, U, V, K>(tds: &Tds<T, U, V, D, K>) -> Result<Self, anyhow::Error>
+    where
+        T: CoordinateScalar<Float = F>,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let mut voronoi = Voronoi {
+            vertices: Vec::new(),
+            rays: Vec::new(),
+            faces: Vec::new(),
+            cells: SecondaryMap::new(),
+        };
+        let mut cell_vertices: HashMap<CellKey, usize> = HashMap::new();
+        let mut faces: HashMap<Vec<VertexKey>, usize> = HashMap::new();
+
+        for (key, cell) in tds.finite_cells() {
+            let index = voronoi.vertices.len();
+            voronoi.vertices.push(VoronoiVertex {
+                cell: key,
+                point: cell.circumcenter(tds)?,
+            });
+            cell_vertices.insert(key, index);
+            for site in cell.vertices.iter() {
+                voronoi.cell_entry(*site).vertices.push(index);
+            }
+            for dual in subfaces(&cell.vertices) {
+                voronoi.face_entry(&mut faces, dual).vertices.push(index);
+            }
+        }
+
+        for hull_facet in tds.convex_hull()?.facets {
+            let facet = hull_facet
+                .facet
+                .ok_or_else(|| anyhow::Error::msg("Hull facet has no cell!"))?;
+            let index = voronoi.rays.len();
+            voronoi.rays.push(VoronoiRay {
+                facet,
+                origin: cell_vertices[&facet.cell],
+                direction: hull_facet.hyperplane.normal,
+            });
+            for site in hull_facet.vertices.iter() {
+                voronoi.cell_entry(*site).rays.push(index);
+            }
+            for dual in subfaces(&hull_facet.vertices) {
+                voronoi.face_entry(&mut faces, dual).rays.push(index);
+            }
+        }
+
+        // The faces of a cell are dual to the faces containing its site,
+        // from the largest dimension
+        let mut order: Vec<usize> = (0..voronoi.faces.len()).collect();
+        order.sort_by_key(|f| voronoi.faces[*f].dual.len());
+        for f in order {
+            for site in voronoi.faces[f].dual.clone() {
+                voronoi.cell_entry(site).faces.push(f);
+            }
+        }
+
+        Ok(voronoi)
+    }
+
+    /// The function `number_of_vertices` returns the number of vertices of
+    /// the [Voronoi] diagram, i.e. of finite cells of the triangulation.
+    pub fn number_of_vertices(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// The function `facets` returns the facets of the [VoronoiCell] of a
+    /// vertex, i.e. its faces of dimension D - 1, each of which is shared
+    /// with the [VoronoiCell] of the other vertex of its dual edge.
+    ///
+    /// # Arguments:
+    ///
+    /// * `site`: The [VertexKey] of the vertex.
+    ///
+    /// # Returns:
+    ///
+    /// An [Iterator] over the facets, which is empty if the vertex has no
+    /// [VoronoiCell].
+    pub fn facets(&self, site: VertexKey) -> impl Iterator<Item = &VoronoiFace> {
+        self.cells
+            .get(site)
+            .into_iter()
+            .flat_map(|cell| cell.faces.iter())
+            .map(|f| &self.faces[*f])
+            .filter(|face| face.dimension + 1 == D)
+    }
+
+    /// The function `clipped_cell` clips the [VoronoiCell] of a vertex to a
+    /// [Domain], and integrates over the clipped cell.
+    ///
+    /// The clipped cell is the intersection of the [Domain] with the
+    /// halfspaces bounded by the bisectors of the edges dual to the facets
+    /// of the [VoronoiCell], which are the hyperplanes through the
+    /// circumcenters of the cells around each edge. Its volume, centroid and
+    /// face areas are computed with Lasserre's recursion over its faces
+    /// (cf. *An analytical expression and an algorithm for the volume of a
+    /// convex polyhedron in R^n*): the volume of a k-dimensional face is the
+    /// sum over its facets of their volumes times their distances from any
+    /// point of its affine hull, divided by k, and similarly for its first
+    /// moment. Only the hyperplanes through a vertex of a face can bound it,
+    /// so the recursion only visits the faces of the clipped cell: its
+    /// vertices are dual to the facets of the convex hull of the polar
+    /// points of the hyperplanes relative to a point inside it, such as the
+    /// vertex itself, or else the center of the largest ball inside the
+    /// clipped cell.
+    ///
+    /// The result is a floating-point approximation, computed in `f64` even
+    /// for exact coordinates, which decides incidences with a tolerance
+    /// relative to the distance `s` from the vertex to the furthest
+    /// hyperplane of the [Domain]:
+    ///
+    /// * a point within `1e-9 * s` of a hyperplane is on it, so a vertex of
+    ///   the clipped cell which nearly lies on more than D hyperplanes, as
+    ///   for nearly cospherical vertices of the triangulation, is taken to
+    ///   lie on all of them, and hyperplanes meeting the cell in a face of
+    ///   lower dimension, such as those of cospherical vertices, give no
+    ///   face,
+    /// * hyperplanes with the same normal within `1e-9` and offsets within
+    ///   `1e-9 * s` coincide, and give a single face,
+    /// * faces with an area of at most `1e-9 * s^(D - 1)` are dropped, such
+    ///   as the tiny facets between nearly cospherical vertices, and so is
+    ///   the cell if its volume is at most `1e-9 * s^D`.
+    ///
+    /// The volume, centroid and areas are otherwise accurate up to rounding,
+    /// and the cells of all the vertices add up to the [Domain] up to errors
+    /// of the order of these tolerances.
+    ///
+    /// # Arguments:
+    ///
+    /// * `tds`: The Delaunay triangulation the [Voronoi] diagram is the
+    ///   dual of.
+    /// * `site`: The [VertexKey] of the vertex.
+    /// * `domain`: The bounded [Domain] to clip the cell to.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [ClippedCell], or `None` if the cell does
+    /// not meet the interior of the [Domain], or an error if the vertex has
+    /// no [VoronoiCell] or the [Domain] is unbounded or degenerate.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use dd_delaunay::delaunay_core::point::Point;
+    /// use dd_delaunay::delaunay_core::triangulation_data_structure::Tds;
+    /// use dd_delaunay::delaunay_core::voronoi::{Boundary, Domain};
+    /// let points = vec![
+    ///     Point::new([0.0, 0.0]),
+    ///     Point::new([2.0, 0.0]),
+    ///     Point::new([2.0, 2.0]),
+    ///     Point::new([0.0, 2.0]),
+    ///     Point::new([1.0, 1.0]),
+    /// ];
+    /// let tds: Tds<f64, usize, usize, 2> = Tds::new(points).bowyer_watson().unwrap();
+    /// let voronoi = tds.voronoi().unwrap();
+    /// let domain = Domain::bounding_box([0.0, 0.0], [2.0, 2.0]);
+    /// let corner = tds.vertices.keys().next().unwrap();
+    /// let cell = voronoi.clipped_cell(&tds, corner, &domain).unwrap().unwrap();
+    /// assert!((cell.volume - 0.5).abs() < 1e-12);
+    /// assert!((cell.centroid.coords[0] - 1.0 / 3.0).abs() < 1e-12);
+    /// assert_eq!(cell.faces.len(), 3);
+    /// let domain_faces = cell.faces.iter().filter(|f| matches!(f.boundary, Boundary::Domain(_)));
+    /// assert!((domain_faces.map(|f| f.area).sum::<f64>() - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn clipped_cell<T, U, V, K>(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+        site: VertexKey,
+        domain: &Domain<F, D>,
+    ) -> Result<Option<ClippedCell<F, D>>, anyhow::Error>
+    where
+        F: CoordinateScalar<Float = F>,
+        T: CoordinateScalar<Float = F>,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        if !self.cells.contains_key(site) {
+            return Err(anyhow::Error::msg("Vertex has no Voronoi cell!"));
+        }
+        if domain.halfspaces.len() <= D {
+            return Err(anyhow::Error::msg("Domain is unbounded!"));
+        }
+        let point = tds.vertices[site].point.coords.each_ref().map(T::to_f64);
+
+        // The bisectors of the edges dual to the facets, then the domain
+        let mut boundaries: Vec<Boundary> = Vec::new();
+        let mut halfspaces: Vec<Halfspace<D>> = Vec::new();
+        for facet in self.facets(site) {
+            let Some(other) = facet.dual.iter().find(|s| **s != site) else {
+                continue;
+            };
+            let other_point = tds.vertices[*other].point.coords.each_ref().map(T::to_f64);
+            let normal: [f64; D] = std::array::from_fn(|j| other_point[j] - point[j]);
+            let midpoint: [f64; D] = std::array::from_fn(|j| (other_point[j] + point[j]) / 2.0);
+            if let Some(halfspace) = Halfspace::new(normal, dot(&normal, &midpoint)) {
+                boundaries.push(Boundary::Neighbor(*other));
+                halfspaces.push(halfspace);
+            }
+        }
+        for (i, hyperplane) in domain.halfspaces.iter().enumerate() {
+            let halfspace = Halfspace::new(
+                hyperplane.normal.each_ref().map(F::to_f64),
+                hyperplane.offset.to_f64(),
+            )
+            .ok_or_else(|| anyhow::Error::msg("Degenerate domain hyperplane!"))?;
+            boundaries.push(Boundary::Domain(i));
+            halfspaces.push(halfspace);
+        }
+
+        // Distances and volumes are relative to the extent of the domain
+        let scale = halfspaces[halfspaces.len() - domain.halfspaces.len()..]
+            .iter()
+            .map(|h| (h.offset - dot(&h.normal, &point)).abs())
+            .fold(f64::MIN_POSITIVE, f64::max);
+        let tolerance = CLIPPING_EPSILON * scale;
+
+        // A point inside the cell, which is the vertex itself unless it is
+        // outside of the domain
+        let inside = halfspaces
+            .iter()
+            .all(|h| h.offset - dot(&h.normal, &point) > tolerance);
+        let center = if inside {
+            point
+        } else {
+            let (center, radius) = chebyshev_center(&halfspaces, point, scale)
+                .ok_or_else(|| anyhow::Error::msg("Domain is unbounded!"))?;
+            if radius <= tolerance {
+                return Ok(None);
+            }
+            center
+        };
+        let vertices = polytope_vertices::<F, D>(&halfspaces, center, tolerance)?;
+
+        let mut lasserre = Lasserre {
+            halfspaces: &halfspaces,
+            vertices: &vertices,
+            tolerance,
+            integrals: HashMap::new(),
+        };
+        let space = Flat {
+            origin: point,
+            projector: std::array::from_fn(|i| std::array::from_fn(|j| (i == j) as u8 as f64)),
+            dimension: D,
+        };
+        let (volume, moment) = lasserre.integrate(Vec::new(), &space);
+        if volume.is_nan() || volume <= CLIPPING_EPSILON * scale.powi(D as i32) {
+            return Ok(None);
+        }
+
+        let mut faces: Vec<ClippedFace<F, D>> = Vec::new();
+        for (i, normal, distance) in lasserre.facets(&[], &space).unwrap_or_default() {
+            let (area, _) = lasserre.integrate(vec![i], &space.restrict(&normal, distance));
+            if area > CLIPPING_EPSILON * scale.powi(D as i32 - 1) {
+                faces.push(ClippedFace {
+                    boundary: boundaries[i],
+                    hyperplane: Hyperplane {
+                        normal: halfspaces[i].normal.map(F::float_from_f64),
+                        offset: F::float_from_f64(halfspaces[i].offset),
+                    },
+                    area: F::float_from_f64(area),
+                });
+            }
+        }
+
+        Ok(Some(ClippedCell {
+            site,
+            volume: F::float_from_f64(volume),
+            centroid: Point::new(moment.map(|m| F::float_from_f64(m / volume))),
+            faces,
+        }))
+    }
+
+    /// The function `clipped_cells` clips the [VoronoiCell] of every vertex
+    /// to a [Domain], as per [Voronoi::clipped_cell], which decomposes the
+    /// [Domain] into the regions closest to each vertex.
+    ///
+    /// # Returns:
+    ///
+    /// A [Result] containing the [ClippedCell]s which meet the interior of
+    /// the [Domain], or an error if the [Domain] is unbounded or
+    /// degenerate.
+    pub fn clipped_cells<T, U, V, K>(
+        &self,
+        tds: &Tds<T, U, V, D, K>,
+        domain: &Domain<F, D>,
+    ) -> Result<Vec<ClippedCell<F, D>>, anyhow::Error>
+    where
+        F: CoordinateScalar<Float = F>,
+        T: CoordinateScalar<Float = F>,
+        U: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        V: Clone + Copy + Eq + Hash + Ord + PartialEq + PartialOrd,
+        K: Kernel<T, D>,
+        [T; D]: Coord,
+    {
+        let mut cells = Vec::new();
+        for site in self.cells.keys() {
+            cells.extend(self.clipped_cell(tds, site, domain)?);
+        }
+
+        Ok(cells)
+    }
+
+    /// Returns the [VoronoiCell] of a site, which is created empty if it
+    /// does not exist yet.
+    fn cell_entry(&mut self, site: VertexKey) -> &mut VoronoiCell {
+        self.cells
+            .entry(site)
+            .expect("Vertex key is not null!")
+            .or_insert_with(|| VoronoiCell {
+                site,
+                ..VoronoiCell::default()
+            })
+    }
+
+    /// Returns the [VoronoiFace] dual to a face of the triangulation, which
+    /// is created empty if it does not exist yet.
+    fn face_entry(
+        &mut self,
+        faces: &mut HashMap<Vec<VertexKey>, usize>,
+        dual: Vec<VertexKey>,
+    ) -> &mut VoronoiFace {
+        let index = *faces.entry(dual.clone()).or_insert_with(|| {
+            self.faces.push(VoronoiFace {
+                dimension: D + 1 - dual.len(),
+                dual,
+                ..VoronoiFace::default()
+            });
+            self.faces.len() - 1
+        });
+
+        &mut self.faces[index]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// The [Domain] struct is a bounded convex polytope, as the intersection of
+/// the negative sides of [Hyperplane]s, to which the cells of a [Voronoi]
+/// diagram are clipped.
+///
+/// # Properties:
+///
+/// * `halfspaces`: The [Hyperplane]s bounding the domain, whose normals
+///   point out of it.
+pub struct Domain<F, const D: usize> {
+    /// The hyperplanes bounding the domain, with outward normals.
+    pub halfspaces: Vec<Hyperplane<F, D>>,
+}
+
+impl<F, const D: usize> Domain<F, D>
+where
+    F: CoordinateScalar<Float = F>,
+{
+    /// The function `bounding_box` returns the axis-aligned box between two
+    /// opposite corners as a [Domain].
+    ///
+    /// # Arguments:
+    ///
+    /// * `lower`: The corner with the smallest coordinates.
+    /// * `upper`: The corner with the largest coordinates.
+    pub fn bounding_box(lower: [F; D], upper: [F; D]) -> Self {
+        let mut halfspaces = Vec::with_capacity(2 * D);
+        for j in 0..D {
+            let mut normal: [F; D] = std::array::from_fn(|_| F::float_from_f64(0.0));
+            normal[j] = F::float_from_f64(-1.0);
+            halfspaces.push(Hyperplane {
+                normal: normal.clone(),
+                offset: F::float_from_f64(-lower[j].to_f64()),
+            });
+            normal[j] = F::float_from_f64(1.0);
+            halfspaces.push(Hyperplane {
+                normal,
+                offset: upper[j].clone(),
+            });
+        }
+
+        Domain { halfspaces }
+    }
+
+    /// The function `from_convex_hull` returns the inside of a [ConvexHull]
+    /// as a [Domain], bounded by the hyperplanes of its facets.
+    pub fn from_convex_hull(hull: &ConvexHull<F, D>) -> Self {
+        Domain {
+            halfspaces: hull.facets.iter().map(|f| f.hyperplane.clone()).collect(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+/// The [Boundary] enum is what bounds a face of a [ClippedCell].
+pub enum Boundary {
+    /// The bisector between the site and another vertex, whose
+    /// [ClippedCell] shares the face.
+    Neighbor(VertexKey),
+    /// The hyperplane with the given index in [Domain::halfspaces].
+    Domain(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+/// The [ClippedFace] struct is a facet of a [ClippedCell].
+///
+/// # Properties:
+///
+/// * `boundary`: The [Boundary] the face lies on.
+/// * `hyperplane`: The [Hyperplane] through the face, whose unit normal
+///   points out of the cell.
+/// * `area`: The (D-1)-dimensional volume of the face.
+pub struct ClippedFace<F, const D: usize> {
+    /// What the face lies on.
+    pub boundary: Boundary,
+    /// The hyperplane through the face, with outward normal.
+    pub hyperplane: Hyperplane<F, D>,
+    /// The area of the face.
+    pub area: F,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+/// The [ClippedCell] struct is the [VoronoiCell] of a vertex clipped to a
+/// [Domain], i.e. the points of the [Domain] closest to the vertex.
+///
+/// # Properties:
+///
+/// * `site`: The [VertexKey] of the vertex.
+/// * `volume`: The D-dimensional volume of the cell.
+/// * `centroid`: The center of mass of the cell.
+/// * `faces`: The [ClippedFace]s bounding the cell.
+pub struct ClippedCell<F, const D: usize>
+where
+    F: CoordinateScalar,
+    [F; D]: Coord,
+{
+    /// The vertex the cell is the region of.
+    pub site: VertexKey,
+    /// The volume of the cell.
+    pub volume: F,
+    /// The centroid of the cell.
+    pub centroid: Point<F, D>,
+    /// The facets of the cell.
+    pub faces: Vec<ClippedFace<F, D>>,
+}
+
+/// The relative tolerance of [Voronoi::clipped_cell] on distances, below
+/// which points lie on hyperplanes and hyperplanes coincide, and on volumes,
+/// below which faces vanish, so that its results are floating-point
+/// approximations rather than exact.
+const CLIPPING_EPSILON: f64 = 1e-9;
+
+/// A halfspace `normal . x <= offset` with a unit normal, in `f64`.
+#[derive(Clone, Copy, Debug)]
+struct Halfspace<const D: usize> {
+    normal: [f64; D],
+    offset: f64,
+}
+
+impl<const D: usize> Halfspace<D> {
+    /// Returns the halfspace with its normal scaled to unit length, or
+    /// `None` if the normal is zero or not finite.
+    fn new(normal: [f64; D], offset: f64) -> Option<Self> {
+        let length = dot(&normal, &normal).sqrt();
+        (length > 0.0 && length.is_finite() && offset.is_finite()).then(|| Halfspace {
+            normal: normal.map(|n| n / length),
+            offset: offset / length,
+        })
+    }
+}
+
+/// An affine subspace of the given `dimension` through the `origin`, onto
+/// whose directions the `projector` projects orthogonally.
+#[derive(Clone, Copy, Debug)]
+struct Flat<const D: usize> {
+    origin: [f64; D],
+    projector: [[f64; D]; D],
+    dimension: usize,
+}
+
+impl<const D: usize> Flat<D> {
+    /// Returns the hyperplane of the flat orthogonal to a unit direction of
+    /// it, at a signed distance from its origin.
+    fn restrict(&self, normal: &[f64; D], distance: f64) -> Self {
+        Flat {
+            origin: std::array::from_fn(|j| self.origin[j] + distance * normal[j]),
+            projector: std::array::from_fn(|i| {
+                std::array::from_fn(|j| self.projector[i][j] - normal[i] * normal[j])
+            }),
+            dimension: self.dimension - 1,
+        }
+    }
+}
+
+/// Lasserre's recursion, which integrates over the faces of the polytope
+/// bounded by the `halfspaces`, identified by the sorted indices of the
+/// halfspaces they lie on, and memoizes their volumes and first moments.
+/// The `vertices` of the polytope, as the sorted indices of the halfspaces
+/// they lie on, restrict the facets of each face to the halfspaces which
+/// its vertices lie on.
+struct Lasserre<'a, const D: usize> {
+    halfspaces: &'a [Halfspace<D>],
+    vertices: &'a [Vec<usize>],
+    tolerance: f64,
+    integrals: HashMap<Vec<usize>, (f64, [f64; D])>,
+}
+
+impl<const D: usize> Lasserre<'_, D> {
+    /// Returns the facets of a face of the polytope in a flat, as the index
+    /// of their halfspace, their unit normal in the flat, and their signed
+    /// distance from its origin, without repeating those on the same
+    /// hyperplane of the flat, or `None` if a halfspace parallel to the
+    /// flat excludes it. Only the halfspaces which a vertex of the face
+    /// lies on can bound it.
+    fn facets(&self, face: &[usize], flat: &Flat<D>) -> Option<Vec<(usize, [f64; D], f64)>> {
+        let mut candidates: Vec<usize> = self
+            .vertices
+            .iter()
+            .filter(|tight| face.iter().all(|f| tight.binary_search(f).is_ok()))
+            .flat_map(|tight| tight.iter().copied())
+            .filter(|i| face.binary_search(i).is_err())
+            .collect();
+        candidates.sort_unstable();
+        candidates.dedup();
+
+        let mut facets: Vec<(usize, [f64; D], f64)> = Vec::new();
+        for i in candidates {
+            let halfspace = &self.halfspaces[i];
+            let projected: [f64; D] =
+                std::array::from_fn(|j| dot(&flat.projector[j], &halfspace.normal));
+            let length = dot(&projected, &projected).sqrt();
+            let slack = halfspace.offset - dot(&halfspace.normal, &flat.origin);
+            if length < CLIPPING_EPSILON {
+                if slack < -self.tolerance {
+                    return None;
+                }
+                continue;
+            }
+
+            let normal = projected.map(|p| p / length);
+            let distance = slack / length;
+            let coincident = facets.iter().any(|(_, other, other_distance)| {
+                (distance - other_distance).abs() <= self.tolerance
+                    && normal
+                        .iter()
+                        .zip(other.iter())
+                        .all(|(a, b)| (a - b).abs() <= CLIPPING_EPSILON)
+            });
+            if !coincident {
+                facets.push((i, normal, distance));
+            }
+        }
+
+        Some(facets)
+    }
+
+    /// Returns the volume and first moment of a face of the polytope, which
+    /// spans the given flat unless it is empty.
+    fn integrate(&mut self, face: Vec<usize>, flat: &Flat<D>) -> (f64, [f64; D]) {
+        if let Some(integral) = self.integrals.get(&face) {
+            return *integral;
+        }
+
+        let integral = if flat.dimension == 0 {
+            let inside = self
+                .halfspaces
+                .iter()
+                .all(|h| dot(&h.normal, &flat.origin) <= h.offset + self.tolerance);
+            if inside {
+                (1.0, flat.origin)
+            } else {
+                (0.0, [0.0; D])
+            }
+        } else {
+            // The divergence of x - origin, and of (x - origin) x_j, over
+            // the face gives the integrals over its facets
+            let mut volume = 0.0;
+            let mut moment = [0.0; D];
+            for (i, normal, distance) in self.facets(&face, flat).unwrap_or_default() {
+                let mut facet = face.clone();
+                let position = facet.partition_point(|f| *f < i);
+                facet.insert(position, i);
+                let (facet_volume, facet_moment) =
+                    self.integrate(facet, &flat.restrict(&normal, distance));
+                volume += distance * facet_volume;
+                for (m, f) in moment.iter_mut().zip(facet_moment.iter()) {
+                    *m += distance * f;
+                }
+            }
+            let dimension = flat.dimension as f64;
+            volume /= dimension;
+            for (m, o) in moment.iter_mut().zip(flat.origin.iter()) {
+                *m = (*m + o * volume) / (dimension + 1.0);
+            }
+            (volume, moment)
+        };
+
+        self.integrals.insert(face, integral);
+        integral
+    }
+}
+
+/// Returns the vertices of the polytope bounded by the halfspaces,
+/// as the sorted indices of the halfspaces they lie on within the
+/// tolerance, from a point strictly inside it.
+///
+/// The polar points `normal / (offset - normal . center)` of the
+/// halfspaces relative to the center have a convex hull whose facets are
+/// dual to the vertices of the polytope, and whose vertices are dual to its
+/// facets. Each hull facet gives the vertex where the halfspaces of its
+/// vertices meet, and the hull facets splitting a face of the hull through
+/// more than D polar points, whose vertex lies on more than D halfspaces,
+/// give the same vertex up to rounding. The indices of a vertex include
+/// those of the hull facet, even if its halfspaces are too close to
+/// parallel to meet in a single point.
+///
+/// # Returns:
+///
+/// A [Result] containing the vertices, or an error if the polytope is
+/// unbounded.
+fn polytope_vertices<F, const D: usize>(
+    halfspaces: &[Halfspace<D>],
+    center: [f64; D],
+    tolerance: f64,
+) -> Result<Vec<Vec<usize>>, anyhow::Error>
+where
+    F: CoordinateScalar<Float = F>,
+    [F; D]: Coord,
+{
+    let polar: Vec<Point<F, D>> = halfspaces
+        .iter()
+        .map(|h| {
+            let slack = h.offset - dot(&h.normal, &center);
+            Point::new(h.normal.map(|n| F::float_from_f64(n / slack)))
+        })
+        .collect();
+    let subset = |indices: &[usize]| -> Vec<Point<F, D>> {
+        indices.iter().map(|i| polar[*i].clone()).collect()
+    };
+    let hull = quickhull(
+        polar.len(),
+        D,
+        |indices| FilteredKernel::affinely_independent(&subset(indices)),
+        |indices| FilteredKernel::orientation(&subset(indices)),
+        |indices| {
+            let last = polar[indices[D]].coords.each_ref().map(F::to_f64);
+            determinant::<D>(std::array::from_fn(|i| {
+                std::array::from_fn(|j| polar[indices[i]].coords[j].to_f64() - last[j])
+            }))
+        },
+    );
+
+    // The origin of the polar points, i.e. the center, is inside their hull
+    // if and only if the polytope is bounded
+    let origin = Point::new(std::array::from_fn(|_| F::float_from_f64(0.0)));
+    let mut vertices: Vec<Vec<usize>> = Vec::new();
+    for (facet, outer) in hull.facets.iter() {
+        let mut points = subset(facet);
+        points.push(origin.clone());
+        if FilteredKernel::orientation(&points) != outer.reverse() {
+            return Err(anyhow::Error::msg("Domain is unbounded!"));
+        }
+
+        let mut tight = facet.clone();
+        if let Some(vertex) = solve(
+            std::array::from_fn(|i| halfspaces[facet[i]].normal),
+            std::array::from_fn(|i| halfspaces[facet[i]].offset),
+        ) {
+            tight.extend((0..halfspaces.len()).filter(|i| {
+                let h = &halfspaces[*i];
+                (h.offset - dot(&h.normal, &vertex)).abs() <= tolerance
+            }));
+        }
+        tight.sort_unstable();
+        tight.dedup();
+        if !vertices.contains(&tight) {
+            vertices.push(tight);
+        }
+    }
+    if vertices.is_empty() {
+        return Err(anyhow::Error::msg("Domain is unbounded!"));
+    }
+
+    Ok(vertices)
+}
+
+/// Returns the center of the largest ball inside the polytope bounded by
+/// the halfspaces, with its radius, which is not positive if the polytope
+/// has no interior, or `None` if the polytope is unbounded.
+///
+/// The linear program maximizing the distance `t` of a point `origin + y`
+/// from the hyperplanes, subject to `normal . y + t <= offset - normal .
+/// origin`, is solved with the simplex method and Bland's rule, from the
+/// feasible basis of the slack variables at `y = 0` and `t = t_0`, with `y`
+/// split into its positive and negative parts and `t - t_0` nonnegative.
+fn chebyshev_center<const D: usize>(
+    halfspaces: &[Halfspace<D>],
+    origin: [f64; D],
+    scale: f64,
+) -> Option<([f64; D], f64)> {
+    let slacks: Vec<f64> = halfspaces
+        .iter()
+        .map(|h| h.offset - dot(&h.normal, &origin))
+        .collect();
+    let start = slacks.iter().copied().fold(f64::INFINITY, f64::min) - scale;
+
+    // The columns of y+, y-, t - t_0 and the slack variables, then the
+    // right hand side, and the reduced costs last
+    let columns = 2 * D + 1 + halfspaces.len();
+    let mut tableau: Vec<Vec<f64>> = halfspaces
+        .iter()
+        .zip(slacks.iter())
+        .enumerate()
+        .map(|(i, (h, slack))| {
+            let mut row = vec![0.0; columns + 1];
+            for j in 0..D {
+                row[j] = h.normal[j];
+                row[D + j] = -h.normal[j];
+            }
+            row[2 * D] = 1.0;
+            row[2 * D + 1 + i] = 1.0;
+            row[columns] = slack - start;
+            row
+        })
+        .collect();
+    let mut costs = vec![0.0; columns + 1];
+    costs[2 * D] = -1.0;
+    let mut basis: Vec<usize> = (0..halfspaces.len()).map(|i| 2 * D + 1 + i).collect();
+
+    let pivot_epsilon = CLIPPING_EPSILON * CLIPPING_EPSILON;
+    while let Some(entering) = (0..columns).find(|j| costs[*j] < -pivot_epsilon) {
+        let leaving = (0..tableau.len())
+            .filter(|r| tableau[*r][entering] > pivot_epsilon)
+            .min_by(|a, b| {
+                let ratio = |r: usize| tableau[r][columns] / tableau[r][entering];
+                ratio(*a)
+                    .total_cmp(&ratio(*b))
+                    .then(basis[*a].cmp(&basis[*b]))
+            })?;
+
+        let pivot = tableau[leaving][entering];
+        for entry in tableau[leaving].iter_mut() {
+            *entry /= pivot;
+        }
+        let pivot_row = tableau[leaving].clone();
+        for row in tableau
+            .iter_mut()
+            .enumerate()
+            .filter(|(r, _)| *r != leaving)
+            .map(|(_, row)| row)
+            .chain(std::iter::once(&mut costs))
+        {
+            let factor = row[entering];
+            if factor != 0.0 {
+                for (entry, p) in row.iter_mut().zip(pivot_row.iter()) {
+                    *entry -= factor * p;
+                }
+            }
+        }
+        basis[leaving] = entering;
+    }
+
+    let mut solution = vec![0.0; columns];
+    for (row, variable) in tableau.iter().zip(basis.iter()) {
+        solution[*variable] = row[columns];
+    }
+    Some((
+        std::array::from_fn(|j| origin[j] + solution[j] - solution[D + j]),
+        start + solution[2 * D],
+    ))
+}
+
+/// Returns the dot product of two vectors.
+fn dot<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Returns the sorted vertices of the faces of a simplex with at least two
+/// vertices, including the simplex itself.
+fn subfaces(vertices: &[VertexKey]) -> impl Iterator<Item = Vec<VertexKey>> + '_ {
+    (0..1usize << vertices.len())
+        .filter(|mask| mask.count_ones() >= 2)
+        .map(move |mask| {
+            let mut face: Vec<VertexKey> = (0..vertices.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .map(|i| vertices[i])
+                .collect();
+            face.sort();
+            face
+        })
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::delaunay_core::test_utilities::random_points;
+
+    fn distance<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+        a.iter()
+            .zip(b.iter())
+            .map(|(x, y)| (x - y) * (x - y))
+            .sum::<f64>()
+            .sqrt()
+    }
+
+    /// Checks that the points of a face are closest to the vertices of its
+    /// dual face, and equally close to them, at its vertices and along its
+    /// rays.
+    fn check_voronoi<const D: usize>(tds: &Tds<f64, usize, usize, D>, voronoi: &Voronoi<f64, D>)
+    where
+        [f64; D]: Coord,
+    {
+        let sites: Vec<[f64; D]> = tds.vertices.values().map(|v| v.point.coords).collect();
+        let check = |point: &[f64; D], dual: &[VertexKey]| {
+            let nearest = sites
+                .iter()
+                .map(|s| distance(s, point))
+                .fold(f64::INFINITY, f64::min);
+            for site in dual {
+                let d = distance(&tds.vertices[*site].point.coords, point);
+                assert!((d - nearest).abs() < 1e-9 * nearest.max(1.0));
+            }
+        };
+
+        for face in voronoi.faces.iter() {
+            assert_eq!(face.dimension + face.dual.len(), D + 1);
+            assert!(!face.vertices.is_empty());
+            for v in face.vertices.iter() {
+                let origin = voronoi.vertices[*v].point.coords;
+                check(&origin, &face.dual);
+                for r in face.rays.iter() {
+                    let ray = &voronoi.rays[*r];
+                    let point = std::array::from_fn(|j| {
+                        voronoi.vertices[ray.origin].point.coords[j] + 10.0 * ray.direction[j]
+                    });
+                    check(&point, &face.dual);
+                }
+            }
+        }
+
+        // Every face of a cell contains its site, and the faces of a cell
+        // dual to an edge are shared with the cell at its other end
+        for (site, cell) in voronoi.cells.iter() {
+            assert_eq!(cell.site, site);
+            for f in cell.faces.iter() {
+                assert!(voronoi.faces[*f].dual.contains(&site));
+            }
+            for facet in voronoi.facets(site) {
+                let other = facet.dual.iter().find(|s| **s != site).unwrap();
+                assert!(voronoi.cells[*other]
+                    .faces
+                    .iter()
+                    .any(|f| voronoi.faces[*f] == *facet));
+            }
+        }
+    }
+
+    /// Checks that the clipped cells of the vertices partition the domain,
+    /// and that the faces they share match.
+    fn check_partition<const D: usize>(
+        tds: &Tds<f64, usize, usize, D>,
+        domain: &Domain<f64, D>,
+        volume: f64,
+        centroid: [f64; D],
+    ) where
+        [f64; D]: Coord,
+    {
+        let cells = tds.voronoi().unwrap().clipped_cells(tds, domain).unwrap();
+        let total: f64 = cells.iter().map(|c| c.volume).sum();
+        assert!(
+            (total - volume).abs() < 1e-9 * volume,
+            "{total} != {volume}"
+        );
+        for (j, expected) in centroid.iter().enumerate() {
+            let moment: f64 = cells.iter().map(|c| c.volume * c.centroid.coords[j]).sum();
+            assert!((moment / total - expected).abs() < 1e-9);
+        }
+
+        for cell in cells.iter() {
+            assert!(cell.volume > 0.0);
+            for face in cell.faces.iter() {
+                let Boundary::Neighbor(other) = face.boundary else {
+                    continue;
+                };
+                let other = cells.iter().find(|c| c.site == other).unwrap();
+                let shared = other
+                    .faces
+                    .iter()
+                    .find(|f| f.boundary == Boundary::Neighbor(cell.site))
+                    .unwrap();
+                assert!((shared.area - face.area).abs() < 1e-9 * face.area.max(1.0));
+                for j in 0..D {
+                    assert!(
+                        (shared.hyperplane.normal[j] + face.hyperplane.normal[j]).abs() < 1e-12
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn voronoi_clipped_square() {
+        let points = vec![
+            Point::new([0.0, 0.0]),
+            Point::new([2.0, 0.0]),
+            Point::new([2.0, 2.0]),
+            Point::new([0.0, 2.0]),
+            Point::new([1.0, 1.0]),
+        ];
+        let tds: Tds<f64, usize, usize, 2> = Tds::new(points).bowyer_watson().unwrap();
+        let voronoi = tds.voronoi().unwrap();
+        let domain = Domain::bounding_box([0.0, 0.0], [2.0, 2.0]);
+
+        // The diamond around the center, and the triangles in the corners
+        let center = tds.vertices.keys().nth(4).unwrap();
+        let cell = voronoi
+            .clipped_cell(&tds, center, &domain)
+            .unwrap()
+            .unwrap();
+        assert!((cell.volume - 2.0).abs() < 1e-12);
+        assert!((cell.centroid.coords[0] - 1.0).abs() < 1e-12);
+        assert!((cell.centroid.coords[1] - 1.0).abs() < 1e-12);
+        assert_eq!(cell.faces.len(), 4);
+        for face in cell.faces.iter() {
+            assert!(matches!(face.boundary, Boundary::Neighbor(_)));
+            assert!((face.area - 2.0_f64.sqrt()).abs() < 1e-12);
+        }
+        check_partition(&tds, &domain, 4.0, [1.0, 1.0]);
+
+        // Inside the diamond, but away from the center
+        let inner = Domain::bounding_box([1.2, 0.9], [1.4, 1.1]);
+        let cell = voronoi.clipped_cell(&tds, center, &inner).unwrap().unwrap();
+        assert!((cell.volume - 0.04).abs() < 1e-12);
+        assert!((cell.centroid.coords[0] - 1.3).abs() < 1e-12);
+        assert!((cell.centroid.coords[1] - 1.0).abs() < 1e-12);
+        assert!(cell
+            .faces
+            .iter()
+            .all(|f| matches!(f.boundary, Boundary::Domain(_))));
+
+        // Away from the domain, or with an unbounded domain
+        let far = Domain::bounding_box([10.0, 10.0], [11.0, 11.0]);
+        assert!(voronoi.clipped_cell(&tds, center, &far).unwrap().is_none());
+        let half_plane = Domain {
+            halfspaces: vec![Hyperplane {
+                normal: [1.0, 0.0],
+                offset: 1.0,
+            }],
+        };
+        assert!(voronoi.clipped_cell(&tds, center, &half_plane).is_err());
+        let flat: Tds<f64, usize, usize, 2> = Tds::new(random_points::<2>(2, 12, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let site = flat.vertices.keys().next().unwrap();
+        assert!(flat
+            .voronoi()
+            .unwrap()
+            .clipped_cell(&flat, site, &domain)
+            .is_err());
+    }
+
+    #[test]
+    fn voronoi_clipped_box() {
+        let tds: Tds<f64, usize, usize, 2> = Tds::new(random_points(100, 13, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let domain = Domain::bounding_box([-1.0, -1.0], [1.0, 1.0]);
+        check_partition(&tds, &domain, 4.0, [0.0, 0.0]);
+
+        // The domain faces cover the boundary of the box
+        let cells = tds.voronoi().unwrap().clipped_cells(&tds, &domain).unwrap();
+        let boundary: f64 = cells
+            .iter()
+            .flat_map(|c| c.faces.iter())
+            .filter(|f| matches!(f.boundary, Boundary::Domain(_)))
+            .map(|f| f.area)
+            .sum();
+        assert!((boundary - 8.0).abs() < 1e-9);
+
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(random_points(60, 14, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let domain = Domain::bounding_box([-0.5, -1.0, -1.0], [1.5, 1.0, 0.5]);
+        check_partition(&tds, &domain, 6.0, [0.5, 0.0, -0.25]);
+
+        let tds: Tds<f64, usize, usize, 4> = Tds::new(random_points(20, 15, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let domain = Domain::bounding_box([-1.0; 4], [1.0; 4]);
+        check_partition(&tds, &domain, 16.0, [0.0; 4]);
+
+        let tds: Tds<f64, usize, usize, 5> = Tds::new(random_points(60, 17, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let domain = Domain::bounding_box([-1.0; 5], [1.0; 5]);
+        check_partition(&tds, &domain, 32.0, [0.0; 5]);
+    }
+
+    #[test]
+    fn voronoi_clipped_polytope() {
+        // A simplex as the domain, with vertices outside of it
+        let corners = vec![
+            Point::new([0.0, 0.0, 0.0]),
+            Point::new([3.0, 0.0, 0.0]),
+            Point::new([0.0, 3.0, 0.0]),
+            Point::new([0.0, 0.0, 3.0]),
+        ];
+        let simplex: Tds<f64, usize, usize, 3> = Tds::new(corners).bowyer_watson().unwrap();
+        let domain = Domain::from_convex_hull(&simplex.convex_hull().unwrap());
+
+        let points = random_points(50, 16, 1.0)
+            .into_iter()
+            .map(|p: Point<f64, 3>| Point::new(p.coords.map(|c| c * 2.0 + 1.0)))
+            .collect();
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(points).bowyer_watson().unwrap();
+        check_partition(&tds, &domain, 4.5, [0.75, 0.75, 0.75]);
+        let voronoi = tds.voronoi().unwrap();
+        assert!(voronoi.clipped_cells(&tds, &domain).unwrap().len() < voronoi.cells.len());
+    }
+
+    #[test]
+    fn voronoi_clipped_grid() {
+        // Cospherical vertices, whose cells are unit cubes
+        let grid: Vec<Point<f64, 3>> = (0..27)
+            .map(|i| Point::new([(i % 3) as f64, (i / 3 % 3) as f64, (i / 9) as f64]))
+            .collect();
+        let tds: Tds<f64, usize, usize, 3> = Tds::new(grid).bowyer_watson().unwrap();
+        let domain = Domain::bounding_box([-0.5; 3], [2.5; 3]);
+        let cells = tds.voronoi().unwrap().clipped_cells(&tds, &domain).unwrap();
+        assert_eq!(cells.len(), 27);
+        for cell in cells.iter() {
+            assert!((cell.volume - 1.0).abs() < 1e-12);
+            let site = tds.vertices[cell.site].point.coords;
+            for (c, s) in cell.centroid.coords.iter().zip(site.iter()) {
+                assert!((c - s).abs() < 1e-12);
+            }
+            assert_eq!(cell.faces.len(), 6);
+            assert!(cell.faces.iter().all(|f| (f.area - 1.0).abs() < 1e-12));
+        }
+        check_partition(&tds, &domain, 27.0, [1.0; 3]);
+    }
+
+    #[test]
+    fn voronoi_square() {
+        let points = vec![
+            Point::new([0.0, 0.0]),
+            Point::new([2.0, 0.0]),
+            Point::new([2.0, 2.0]),
+            Point::new([0.0, 2.0]),
+            Point::new([1.0, 1.0]),
+        ];
+        let tds: Tds<f64, usize, usize, 2> = Tds::new(points).bowyer_watson().unwrap();
+        let voronoi = tds.voronoi().unwrap();
+        check_voronoi(&tds, &voronoi);
+
+        // The cell of the center is the diamond of the circumcenters
+        let center = tds.vertices.keys().nth(4).unwrap();
+        let cell = &voronoi.cells[center];
+        assert!(cell.is_bounded());
+        let mut diamond: Vec<[f64; 2]> = cell
+            .vertices
+            .iter()
+            .map(|v| voronoi.vertices[*v].point.coords)
+            .collect();
+        diamond.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(diamond, [[0.0, 1.0], [1.0, 0.0], [1.0, 2.0], [2.0, 1.0]]);
+        assert_eq!(voronoi.facets(center).count(), 4);
+        assert!(voronoi.facets(center).all(|f| f.is_bounded()));
+
+        // The cells of the corners are unbounded between two rays
+        for (site, cell) in voronoi.cells.iter() {
+            if site != center {
+                assert!(!cell.is_bounded());
+                assert_eq!(cell.rays.len(), 2);
+                assert_eq!(voronoi.facets(site).count(), 3);
+            }
+        }
+        assert_eq!(voronoi.number_of_vertices(), 4);
+        assert_eq!(voronoi.rays.len(), 4);
+        assert_eq!(voronoi.faces.iter().filter(|f| f.dimension == 1).count(), 8);
+    }
+
+    #[test]
+    fn voronoi_random() {
+        let tds: Tds<f64, usize, usize, 3> =
+            Tds::new(random_points(60, 7, 1.0)).bowyer_watson().unwrap();
+        let voronoi = tds.voronoi().unwrap();
+        check_voronoi(&tds, &voronoi);
+        assert_eq!(voronoi.cells.len(), tds.number_of_vertices());
+        assert_eq!(voronoi.number_of_vertices(), tds.number_of_cells());
+
+        // The unbounded cells are those of the vertices on the hull
+        let hull = tds.convex_hull().unwrap().vertices();
+        for (site, cell) in voronoi.cells.iter() {
+            assert_eq!(cell.is_bounded(), !hull.contains(&site));
+        }
+
+        let tds: Tds<f64, usize, usize, 4> =
+            Tds::new(random_points(30, 8, 1.0)).bowyer_watson().unwrap();
+        check_voronoi(&tds, &tds.voronoi().unwrap());
+    }
+
+    #[test]
+    fn voronoi_infinite_and_empty() {
+        let mut tds: Tds<f64, usize, usize, 2> =
+            Tds::new(random_points(30, 9, 1.0)).bowyer_watson().unwrap();
+        let voronoi = tds.voronoi().unwrap();
+        tds.add_infinite_cells().unwrap();
+        assert_eq!(tds.voronoi().unwrap(), voronoi);
+
+        let flat: Tds<f64, usize, usize, 3> = Tds::new(random_points::<3>(3, 10, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let voronoi = flat.voronoi().unwrap();
+        assert!(voronoi.cells.is_empty());
+        assert_eq!(voronoi.number_of_vertices(), 0);
+
+        let line: Tds<f64, usize, usize, 1> = Tds::new(random_points::<1>(5, 11, 1.0))
+            .bowyer_watson()
+            .unwrap();
+        let voronoi = line.voronoi().unwrap();
+        check_voronoi(&line, &voronoi);
+        assert_eq!(voronoi.rays.len(), 2);
+        assert_eq!(voronoi.cells.values().filter(|c| c.is_bounded()).count(), 3);
+    }
+}
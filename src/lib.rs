@@ -5,9 +5,12 @@
 //! inspired by [CGAL](https://www.cgal.org).
 //!
 //! # Features
-//! * d-dimensional Delaunay triangulations
+//! * d-dimensional Delaunay triangulations, incrementally or by lifting to a paraboloid
+//! * Convex hulls of triangulations, or of points with Quickhull, with oriented facets and their adjacency
+//! * Voronoi diagrams as the dual of triangulations, with rays for unbounded cells, and cells clipped to a domain with floating-point approximations of their volumes, centroids and face areas
 //! * Arbitrary data types associated with vertices and cells
 //! * Serialization/Deserialization with [serde](https://serde.rs)
+//! * Parallel construction with [rayon](https://github.com/rayon-rs/rayon) behind the `parallel` feature
 
 #[cfg(feature = "serde")]
 use serde::{de::DeserializeOwned, Serialize};
@@ -19,34 +22,46 @@ extern crate derive_builder;
 /// for the library.
 pub mod delaunay_core {
     pub mod cell;
+    pub mod convex_hull;
     pub mod facet;
+    pub mod kernel;
     pub mod matrix;
     pub mod point;
+    pub mod predicates;
+    pub mod spatial_sort;
+    #[cfg(test)]
+    pub(crate) mod test_utilities;
     pub mod triangulation_data_structure;
     pub mod utilities;
     pub mod vertex;
+    pub mod voronoi;
     // Re-export the `delaunay_core` modules.
     pub use cell::*;
+    pub use convex_hull::*;
     pub use facet::*;
+    pub use kernel::*;
     pub use matrix::*;
     pub use point::*;
+    pub use predicates::*;
+    pub use spatial_sort::*;
     pub use triangulation_data_structure::*;
     pub use utilities::*;
     pub use vertex::*;
+    pub use voronoi::*;
 }
 
 // Make use of serde when enabled
 /// The bounds of coord arrays
 #[cfg(feature = "serde")]
-pub trait Coord: Copy + Default + DeserializeOwned + Serialize + Sized {}
+pub trait Coord: Clone + Default + DeserializeOwned + Serialize + Sized {}
 /// The bounds of coord arrays
 #[cfg(not(feature = "serde"))]
-pub trait Coord: Copy + Default + Sized {}
+pub trait Coord: Clone + Default + Sized {}
 
 #[cfg(feature = "serde")]
-impl<T: Copy + Default + DeserializeOwned + Serialize + Sized> Coord for T {}
+impl<T: Clone + Default + DeserializeOwned + Serialize + Sized> Coord for T {}
 #[cfg(not(feature = "serde"))]
-impl<T: Copy + Default + Sized> Coord for T {}
+impl<T: Clone + Default + Sized> Coord for T {}
 
 /// The bounds of `f64` coord arrays
 #[cfg(feature = "serde")]
@@ -83,8 +98,9 @@ mod lib_tests {
         assert!(is_normal::<Point<f64, 3>>());
         assert!(is_normal::<Point<f32, 3>>());
         assert!(is_normal::<Vertex<f64, Option<()>, 3>>());
-        assert!(is_normal::<Facet<f64, Option<()>, Option<()>, 3>>());
+        assert!(is_normal::<Facet>());
         assert!(is_normal::<Cell<f64, Option<()>, Option<()>, 4>>());
         assert!(is_normal::<Tds<f64, Option<()>, Option<()>, 4>>());
+        assert!(is_normal::<Tds<f32, Option<()>, Option<()>, 3>>());
     }
 }